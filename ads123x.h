@@ -0,0 +1,65 @@
+/*
+ * C API of the ads123x driver, which is available with the `ffi` feature
+ *
+ * See the documentation of the `ffi` module for details on every function.
+ */
+
+#ifndef ADS123X_H
+#define ADS123X_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define ADS123X_OK 0
+#define ADS123X_ERR_INVALID_ARGUMENT -1
+#define ADS123X_ERR_INVALID_CHANNEL -2
+#define ADS123X_ERR_WAKE_TIMEOUT -3
+#define ADS123X_ERR_UNSTABLE -4
+#define ADS123X_ERR_INVALID_CALIBRATION -5
+#define ADS123X_ERR_OTHER -6
+#define ADS123X_ERR_OVERFLOW -7
+#define ADS123X_ERR_CALIBRATION_TIMEOUT -8
+
+/* Functions through which the driver accesses the hardware, each of which is
+ * passed `context`. Only `write_a0`, `write_a1`, and `now_us` may be NULL. */
+typedef struct {
+    void *context;
+    bool (*read_dout)(void *context);
+    void (*write_sclk)(void *context, bool high);
+    void (*write_pwdn)(void *context, bool high);
+    void (*write_a0)(void *context, bool high);
+    void (*write_a1)(void *context, bool high);
+    void (*delay_ns)(void *context, uint32_t ns);
+    uint64_t (*now_us)(void *context);
+} ads123x_callbacks;
+
+typedef struct ads123x_handle ads123x_handle;
+
+size_t ads123x_handle_size(void);
+size_t ads123x_handle_align(void);
+
+/* `model` is one of 1232, 1234, 1130, or 1131. Returns NULL if any argument is
+ * invalid. */
+ads123x_handle *ads123x_init(void *storage, size_t len, uint32_t model,
+                             const ads123x_callbacks *callbacks);
+
+int32_t ads123x_reset(ads123x_handle *handle);
+int32_t ads123x_read(ads123x_handle *handle, uint8_t channel, int32_t *value);
+int32_t ads123x_tare(ads123x_handle *handle, uint8_t channel, uint32_t n,
+                     int32_t *offset);
+int32_t ads123x_calibrate_offset(ads123x_handle *handle);
+int32_t ads123x_calibrate_gain(ads123x_handle *handle, uint8_t channel,
+                               int32_t expected, uint32_t n, int32_t *gain);
+int32_t ads123x_standby(ads123x_handle *handle);
+int32_t ads123x_wake(ads123x_handle *handle);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ADS123X_H */
@@ -0,0 +1,151 @@
+//! Message based access to a converter owned by a single task
+//!
+//! An [`Actor`] takes ownership of a driver and runs a loop that receives
+//! [`Command`]s from an [`Inbox`] and publishes a [`Response`] for each of them
+//! to an [`Outbox`]. The rest of the firmware then interacts with the
+//! converter through whatever queue the two traits are implemented for,
+//! instead of sharing a mutable driver between tasks.
+
+use core::future::Future;
+
+use embedded_hal::digital::{InputPin, OutputPin, StatefulOutputPin};
+use embedded_hal_async::{delay::DelayNs, digital::Wait};
+
+use crate::{ADSModel, AdsHooks, Clock, Error, TimingProfile, ADS123X};
+
+/// A request for the [`Actor`] to perform
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Command<Ch> {
+    /// Read the corrected value of a channel
+    Read(Ch),
+    /// Measure and store the software offset of the default channel (see
+    /// [`ADS123X::set_default_channel`])
+    Tare,
+    /// Run the chip's internal offset calibration
+    Calibrate,
+    /// Put the chip into standby mode until the next command
+    Standby,
+}
+
+/// The result of a [`Command`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Response<Ch> {
+    /// The corrected value read for [`Command::Read`]
+    Sample { channel: Ch, value: i32 },
+    /// The software offset stored for [`Command::Tare`]
+    Tared { channel: Ch, offset: i32 },
+    /// The internal offset calibration has completed after the given number
+    /// of microseconds
+    Calibrated { duration_us: u64 },
+    /// The command failed
+    Failed(Error),
+    /// The chip has entered standby mode
+    Standby,
+}
+
+/// A source of commands, such as the receiving end of a channel
+pub trait Inbox<Ch> {
+    /// Waits for the next command
+    fn receive(&mut self) -> impl Future<Output = Command<Ch>>;
+}
+
+/// A sink for responses, such as the sending end of a channel
+pub trait Outbox<Ch> {
+    /// Publishes a response, waiting for space in the queue if necessary
+    fn send(&mut self, response: Response<Ch>) -> impl Future<Output = ()>;
+}
+
+/// A driver that is only accessed through [`Command`]s
+pub struct Actor<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>,
+    tare_samples: u32,
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> Actor<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin + Wait,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    pub fn new(ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>) -> Self {
+        Self {
+            ads,
+            tare_samples: 16,
+        }
+    }
+
+    /// Sets the number of reads averaged for [`Command::Tare`] (16 by default)
+    pub fn with_tare_samples(mut self, n: u32) -> Self {
+        self.tare_samples = n;
+        self
+    }
+
+    /// Releases the driver
+    pub fn free(self) -> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> {
+        self.ads
+    }
+
+    /// Performs a single command and returns its response
+    pub async fn execute(
+        &mut self,
+        delay: &mut impl DelayNs,
+        command: Command<M::Channel>,
+    ) -> Response<M::Channel> {
+        match command {
+            Command::Read(channel) => Response::Sample {
+                channel,
+                value: self.ads.read_calibrated(delay, channel).await.corrected,
+            },
+            Command::Tare => {
+                let channel = self.ads.default_channel();
+                let offset = self
+                    .ads
+                    .measure_software_offset(delay, channel, self.tare_samples)
+                    .await;
+
+                Response::Tared { channel, offset }
+            }
+            Command::Calibrate => match self.ads.calibrate_offset(delay).await {
+                Ok(duration_us) => Response::Calibrated { duration_us },
+                Err(error) => Response::Failed(error),
+            },
+            Command::Standby => {
+                self.ads.enter_standby().await;
+                Response::Standby
+            }
+        }
+    }
+
+    /// Receives commands and publishes their responses forever
+    pub async fn run(
+        &mut self,
+        delay: &mut impl DelayNs,
+        inbox: &mut impl Inbox<M::Channel>,
+        outbox: &mut impl Outbox<M::Channel>,
+    ) -> ! {
+        loop {
+            let command = inbox.receive().await;
+            let response = self.execute(delay, command).await;
+            outbox.send(response).await;
+        }
+    }
+}
@@ -0,0 +1,498 @@
+//! Activity-dependent sampling for mostly idle inputs
+//!
+//! An [`Adaptive`] sampler reads a channel once per idle interval and keeps the
+//! chip in standby in between, until a reading differs from the last settled
+//! one by more than a threshold. It then reads continuously (at 80 SPS if the
+//! SPEED pin is controlled by a [`SpeedPin`]) until a number of consecutive
+//! readings agree with each other again, and drops back to idle sampling.
+//!
+//! This suits inputs such as shelf scales, which are idle almost all of the
+//! time but should respond quickly when something is placed on them.
+//!
+//! Inputs that are read continuously can instead use an [`AutoSpeed`]
+//! sampler, which only switches the SPEED pin: it reads at 80 SPS while the
+//! reading is changing, and at 10 SPS (with lower noise) once it has settled.
+
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{InputPin, OutputPin, StatefulOutputPin},
+};
+
+use crate::{ADSModel, AdsHooks, Clock, Error, PowerState, Speed, TimingProfile, ADS123X};
+
+/// Something that can select the data rate of the chip at runtime
+pub trait SpeedControl {
+    /// Selects the given data rate, returning `false` if the rate can't be
+    /// changed
+    fn set_speed(&mut self, speed: Speed) -> bool;
+}
+
+/// Default for boards where the SPEED pin is strapped, leaving the data rate
+/// in the [`crate::Config`] unchanged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NoSpeedPin;
+
+impl SpeedControl for NoSpeedPin {
+    fn set_speed(&mut self, _speed: Speed) -> bool {
+        false
+    }
+}
+
+/// A GPIO driving the SPEED pin, which is set high for 80 SPS and low for 10
+/// SPS
+pub struct SpeedPin<P>(pub P);
+
+impl<P: OutputPin> SpeedControl for SpeedPin<P> {
+    fn set_speed(&mut self, speed: Speed) -> bool {
+        match speed {
+            Speed::Sps10 => self.0.set_low().unwrap(),
+            Speed::Sps80 => self.0.set_high().unwrap(),
+        }
+
+        true
+    }
+}
+
+/// Whether an [`Adaptive`] sampler is currently reading slowly or continuously
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SamplingMode {
+    /// One reading per idle interval, with the chip in standby in between
+    Idle,
+    /// Continuous readings until the input settles
+    Active,
+}
+
+/// A channel that is sampled slowly until its reading changes
+pub struct Adaptive<DOUT, SCLK, PWDN, A0, A1, M, C, H, T, S = NoSpeedPin>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+    S: SpeedControl,
+{
+    ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>,
+    channel: M::Channel,
+    speed: S,
+    threshold: u32,
+    settle_reads: u32,
+    idle_interval_us: u32,
+    mode: SamplingMode,
+    reference: Option<i32>,
+    last: i32,
+    stable_reads: u32,
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> Adaptive<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Creates a sampler for the given channel that switches to continuous
+    /// reads when a reading differs from the settled one by more than
+    /// `threshold` counts
+    ///
+    /// By default the channel is read once per second while idle, and is
+    /// considered settled again after 8 consecutive readings within
+    /// `threshold` of each other.
+    pub fn new(
+        ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>,
+        channel: M::Channel,
+        threshold: u32,
+    ) -> Self {
+        Self {
+            ads,
+            channel,
+            speed: NoSpeedPin,
+            threshold,
+            settle_reads: 8,
+            idle_interval_us: 1_000_000,
+            mode: SamplingMode::Idle,
+            reference: None,
+            last: 0,
+            stable_reads: 0,
+        }
+    }
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T, S> Adaptive<DOUT, SCLK, PWDN, A0, A1, M, C, H, T, S>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+    S: SpeedControl,
+{
+    /// Lets the sampler drive the SPEED pin, so that it reads at 10 SPS while
+    /// idle and at 80 SPS while active
+    ///
+    /// The pin is set low right away, and the SPEED in the driver's
+    /// [`crate::Config`] is kept in sync with it.
+    pub fn with_speed_pin<P: OutputPin>(
+        mut self,
+        pin: P,
+    ) -> Adaptive<DOUT, SCLK, PWDN, A0, A1, M, C, H, T, SpeedPin<P>> {
+        let mut speed = SpeedPin(pin);
+        speed.set_speed(Speed::Sps10);
+
+        let mut config = *self.ads.config();
+        config.speed = Speed::Sps10;
+        self.ads.set_config(config);
+
+        Adaptive {
+            ads: self.ads,
+            channel: self.channel,
+            speed,
+            threshold: self.threshold,
+            settle_reads: self.settle_reads,
+            idle_interval_us: self.idle_interval_us,
+            mode: self.mode,
+            reference: self.reference,
+            last: self.last,
+            stable_reads: self.stable_reads,
+        }
+    }
+
+    /// Sets the time between readings while idle
+    pub fn with_idle_interval_us(mut self, idle_interval_us: u32) -> Self {
+        self.idle_interval_us = idle_interval_us;
+        self
+    }
+
+    /// Sets the time between readings while idle
+    #[cfg(feature = "fugit")]
+    pub fn with_idle_interval(self, idle_interval: fugit::MicrosDurationU32) -> Self {
+        self.with_idle_interval_us(idle_interval.ticks())
+    }
+
+    /// Sets the number of consecutive readings within the threshold of each
+    /// other after which the input is considered settled
+    pub fn with_settle_reads(mut self, settle_reads: u32) -> Self {
+        self.settle_reads = settle_reads;
+        self
+    }
+
+    /// Releases the driver and the speed control
+    pub fn free(self) -> (ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>, S) {
+        (self.ads, self.speed)
+    }
+
+    pub fn mode(&self) -> SamplingMode {
+        self.mode
+    }
+
+    /// Selects the data rate if the SPEED pin is controlled, and returns
+    /// whether it was changed
+    fn switch_speed(&mut self, speed: Speed) -> bool {
+        if self.ads.config().speed == speed || !self.speed.set_speed(speed) {
+            return false;
+        }
+
+        let mut config = *self.ads.config();
+        config.speed = speed;
+        self.ads.set_config(config);
+
+        true
+    }
+
+    /// Updates the mode with a new reading, returning the data rate to switch
+    /// to if the mode changed
+    fn update(&mut self, value: i32) -> Option<Speed> {
+        match self.mode {
+            SamplingMode::Idle => {
+                let reference = *self.reference.get_or_insert(value);
+
+                if value.abs_diff(reference) <= self.threshold {
+                    return None;
+                }
+
+                self.mode = SamplingMode::Active;
+                self.stable_reads = 0;
+                self.last = value;
+
+                Some(Speed::Sps80)
+            }
+            SamplingMode::Active => {
+                if value.abs_diff(self.last) <= self.threshold {
+                    self.stable_reads += 1;
+                } else {
+                    self.stable_reads = 0;
+                }
+
+                self.last = value;
+
+                if self.stable_reads < self.settle_reads {
+                    return None;
+                }
+
+                self.mode = SamplingMode::Idle;
+                self.reference = Some(value);
+
+                Some(Speed::Sps10)
+            }
+        }
+    }
+
+    /// Reads the channel once and returns the corrected value, sleeping
+    /// through the idle interval first when idle
+    ///
+    /// While idle the chip is put into standby after every reading and woken
+    /// for the next one, which fails with [`Error::WakeTimeout`] if it doesn't
+    /// respond. When the SPEED pin is switched, the conversion that was in
+    /// progress at the old rate is thrown away.
+    pub fn read_blocking(&mut self, delay: &mut impl DelayNs) -> Result<i32, Error> {
+        if self.ads.power_state() == PowerState::Standby {
+            self.ads.sleep_blocking(delay, self.idle_interval_us);
+            self.ads.wake_blocking(delay)?;
+        }
+
+        let value = self
+            .ads
+            .read_calibrated_blocking(delay, self.channel)
+            .corrected;
+
+        if let Some(speed) = self.update(value) {
+            if self.switch_speed(speed) {
+                self.ads.read_calibrated_blocking(delay, self.channel);
+            }
+        }
+
+        if self.mode == SamplingMode::Idle {
+            self.ads.enter_standby_blocking(delay);
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T, S> Adaptive<DOUT, SCLK, PWDN, A0, A1, M, C, H, T, S>
+where
+    DOUT: InputPin + embedded_hal_async::digital::Wait,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+    S: SpeedControl,
+{
+    /// Reads the channel once asynchronously and returns the corrected value,
+    /// sleeping through the idle interval first when idle
+    ///
+    /// See [`Self::read_blocking`] for details.
+    pub async fn read(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> Result<i32, Error> {
+        if self.ads.power_state() == PowerState::Standby {
+            delay.delay_us(self.idle_interval_us).await;
+            self.ads.wake(delay).await?;
+        }
+
+        let value = self
+            .ads
+            .read_calibrated(delay, self.channel)
+            .await
+            .corrected;
+
+        if let Some(speed) = self.update(value) {
+            if self.switch_speed(speed) {
+                self.ads.read_calibrated(delay, self.channel).await;
+            }
+        }
+
+        if self.mode == SamplingMode::Idle {
+            self.ads.enter_standby().await;
+        }
+
+        Ok(value)
+    }
+}
+
+/// A channel that is read continuously, at 80 SPS while its reading changes
+/// and at 10 SPS once it has settled
+pub struct AutoSpeed<DOUT, SCLK, PWDN, A0, A1, M, C, H, T, S>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+    S: SpeedControl,
+{
+    ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>,
+    channel: M::Channel,
+    speed: S,
+    threshold: u32,
+    settle_reads: u32,
+    last: Option<i32>,
+    stable_reads: u32,
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T, S> AutoSpeed<DOUT, SCLK, PWDN, A0, A1, M, C, H, T, S>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+    S: SpeedControl,
+{
+    /// Creates a sampler for the given channel that drives the SPEED pin
+    /// through `speed`, and considers the reading to be changing while
+    /// consecutive readings differ by more than `threshold` counts
+    ///
+    /// The sampler starts out at 80 SPS, and by default drops to 10 SPS after
+    /// 8 consecutive readings within `threshold` of each other. The SPEED in
+    /// the driver's [`crate::Config`] is kept in sync with the pin.
+    pub fn new(
+        mut ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>,
+        channel: M::Channel,
+        mut speed: S,
+        threshold: u32,
+    ) -> Self {
+        if speed.set_speed(Speed::Sps80) {
+            let mut config = *ads.config();
+            config.speed = Speed::Sps80;
+            ads.set_config(config);
+        }
+
+        Self {
+            ads,
+            channel,
+            speed,
+            threshold,
+            settle_reads: 8,
+            last: None,
+            stable_reads: 0,
+        }
+    }
+
+    /// Sets the number of consecutive readings within the threshold of each
+    /// other after which the reading is considered settled
+    pub fn with_settle_reads(mut self, settle_reads: u32) -> Self {
+        self.settle_reads = settle_reads;
+        self
+    }
+
+    /// Releases the driver and the speed control
+    pub fn free(self) -> (ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>, S) {
+        (self.ads, self.speed)
+    }
+
+    /// Returns the data rate the channel is currently read at
+    pub fn speed(&self) -> Speed {
+        self.ads.config().speed
+    }
+
+    /// Updates the settling state with a new reading, and switches the data
+    /// rate if needed, returning whether it was changed
+    fn update(&mut self, value: i32) -> bool {
+        let changing = self
+            .last
+            .is_some_and(|last| value.abs_diff(last) > self.threshold);
+        self.last = Some(value);
+
+        self.stable_reads = if changing {
+            0
+        } else {
+            self.stable_reads.saturating_add(1)
+        };
+
+        let speed = match self.speed() {
+            Speed::Sps80 if self.stable_reads >= self.settle_reads => Speed::Sps10,
+            Speed::Sps10 if changing => Speed::Sps80,
+            _ => return false,
+        };
+
+        if !self.speed.set_speed(speed) {
+            return false;
+        }
+
+        let mut config = *self.ads.config();
+        config.speed = speed;
+        self.ads.set_config(config);
+
+        true
+    }
+
+    /// Reads the channel once and returns the corrected value
+    ///
+    /// When the SPEED pin is switched, the conversion that was in progress at
+    /// the old rate is thrown away before the next reading, so that every
+    /// returned value comes from a conversion at a single rate.
+    pub fn read_blocking(&mut self, delay: &mut impl DelayNs) -> i32 {
+        let value = self
+            .ads
+            .read_calibrated_blocking(delay, self.channel)
+            .corrected;
+
+        if self.update(value) {
+            self.ads.read_calibrated_blocking(delay, self.channel);
+        }
+
+        value
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T, S> AutoSpeed<DOUT, SCLK, PWDN, A0, A1, M, C, H, T, S>
+where
+    DOUT: InputPin + embedded_hal_async::digital::Wait,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+    S: SpeedControl,
+{
+    /// Reads the channel once asynchronously and returns the corrected value
+    ///
+    /// See [`Self::read_blocking`] for details.
+    pub async fn read(&mut self, delay: &mut impl embedded_hal_async::delay::DelayNs) -> i32 {
+        let value = self
+            .ads
+            .read_calibrated(delay, self.channel)
+            .await
+            .corrected;
+
+        if self.update(value) {
+            self.ads.read_calibrated(delay, self.channel).await;
+        }
+
+        value
+    }
+}
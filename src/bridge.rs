@@ -0,0 +1,119 @@
+//! Transfer functions of ratiometric bridge sensors
+//!
+//! Load cells, pressure sensors, torque sensors, and strain gauges are all
+//! Wheatstone bridges whose output is proportional to both the measured
+//! quantity and the excitation voltage. A [`Bridge`] captures a sensor's
+//! datasheet rating (its output at full capacity, and the output at zero) and
+//! maps between [`BridgeOutput`] and the measured quantity, in whatever unit
+//! the capacity is given in (e.g. pascals, millinewton meters, or milligrams).
+//!
+//! The driver converts readings to and from [`BridgeOutput`] with
+//! [`ADS123X::counts_to_bridge_output`] and
+//! [`ADS123X::bridge_output_to_counts`].
+//!
+//! [`ADS123X::counts_to_bridge_output`]: crate::ADS123X::counts_to_bridge_output
+//! [`ADS123X::bridge_output_to_counts`]: crate::ADS123X::bridge_output_to_counts
+
+use crate::{
+    units::{saturate, BridgeOutput},
+    Gain,
+};
+
+/// The rated transfer function of a bridge sensor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Bridge {
+    rated_output: BridgeOutput,
+    capacity: i64,
+    zero_output: BridgeOutput,
+}
+
+impl Bridge {
+    /// Creates a bridge that outputs `rated_output` at `capacity` and nothing
+    /// at zero
+    pub const fn new(rated_output: BridgeOutput, capacity: i64) -> Self {
+        Self {
+            rated_output,
+            capacity,
+            zero_output: BridgeOutput::ZERO,
+        }
+    }
+
+    /// Sets the output at zero (the zero balance from the datasheet or
+    /// calibration certificate)
+    pub const fn with_zero_output(mut self, zero_output: BridgeOutput) -> Self {
+        self.zero_output = zero_output;
+        self
+    }
+
+    pub const fn rated_output(&self) -> BridgeOutput {
+        self.rated_output
+    }
+
+    pub const fn capacity(&self) -> i64 {
+        self.capacity
+    }
+
+    pub const fn zero_output(&self) -> BridgeOutput {
+        self.zero_output
+    }
+
+    /// Converts the bridge output to the measured quantity (truncated towards
+    /// zero and saturating at the bounds of an i64), or returns `None` if the
+    /// rated output is zero
+    pub const fn output_to_value(&self, output: BridgeOutput) -> Option<i64> {
+        let span = self.rated_output.nanovolts_per_volt() as i128;
+
+        if span == 0 {
+            return None;
+        }
+
+        let net =
+            output.nanovolts_per_volt() as i128 - self.zero_output.nanovolts_per_volt() as i128;
+
+        Some(saturate(net.saturating_mul(self.capacity as i128) / span))
+    }
+
+    /// Converts the measured quantity to the output it produces (truncated
+    /// towards zero and saturating at the bounds of an i64), or returns `None`
+    /// if the capacity is zero
+    pub const fn value_to_output(&self, value: i64) -> Option<BridgeOutput> {
+        if self.capacity == 0 {
+            return None;
+        }
+
+        let net =
+            value as i128 * self.rated_output.nanovolts_per_volt() as i128 / self.capacity as i128;
+
+        Some(BridgeOutput::from_nanovolts_per_volt(saturate(
+            net + self.zero_output.nanovolts_per_volt() as i128,
+        )))
+    }
+
+    /// Returns the highest PGA gain whose input range still covers the output
+    /// at full capacity (plus the zero output), for a bridge excited with
+    /// `excitation_uv` microvolts and a reference of `vref_uv` microvolts
+    ///
+    /// Returns `None` if the output exceeds the input range even at a gain of
+    /// 1.
+    pub const fn best_gain(&self, excitation_uv: u32, vref_uv: u32) -> Option<Gain> {
+        let output = self.rated_output.nanovolts_per_volt().unsigned_abs() as u128
+            + self.zero_output.nanovolts_per_volt().unsigned_abs() as u128;
+        let input_nv = output * excitation_uv as u128 / 1_000_000;
+
+        // The input range at a given gain is ±0.5 * VREF / gain
+        let range_nv = vref_uv as u128 * 1_000 / 2;
+
+        if input_nv * 128 <= range_nv {
+            Some(Gain::X128)
+        } else if input_nv * 64 <= range_nv {
+            Some(Gain::X64)
+        } else if input_nv * 2 <= range_nv {
+            Some(Gain::X2)
+        } else if input_nv <= range_nv {
+            Some(Gain::X1)
+        } else {
+            None
+        }
+    }
+}
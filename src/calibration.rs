@@ -0,0 +1,241 @@
+//! Persisting calibration data
+//!
+//! A [`Calibration`] captures the software offset, system gain, and linearity
+//! correction of every channel, along with the corner trims of a
+//! [`crate::platform::Platform`], so that they can be written to non-volatile
+//! memory after calibrating and restored on the next boot. The serialized blob
+//! is laid out as follows, with all multi-byte fields in little endian order:
+//!
+//! | Offset | Size | Field                                              |
+//! |--------|------|----------------------------------------------------|
+//! | 0      | 4    | Magic bytes `ADSC`                                 |
+//! | 4      | 1    | Format version ([`CALIBRATION_VERSION`])           |
+//! | 5      | 3    | Reserved (zero)                                    |
+//! | 8      | 16   | Software offsets for each channel                  |
+//! | 24     | 16   | System gains for each channel                      |
+//! | 40     | 16   | Linearity corrections for each channel             |
+//! | 56     | 16   | Platform trims for each channel                    |
+//! | 72     | 4    | CRC-32 of all of the preceding bytes               |
+//!
+//! Blobs of version 1, which end with the CRC at offset 56, are still accepted
+//! and restore unity trims.
+
+use embedded_hal::digital::{InputPin, OutputPin, StatefulOutputPin};
+
+use crate::{
+    crc::crc32, ADSModel, AdsHooks, Clock, Error, TimingProfile, ADS123X, MAX_CHANNELS, UNITY_GAIN,
+};
+
+#[cfg(feature = "std")]
+use crate::{GAIN_FRACTIONAL_BITS, LINEARITY_FRACTIONAL_BITS};
+
+/// Length of a serialized [`Calibration`] in bytes
+pub const CALIBRATION_LEN: usize = 76;
+
+/// Version of the serialized format written by [`Calibration::to_bytes`]
+pub const CALIBRATION_VERSION: u8 = 2;
+
+/// Length of a version 1 blob, which has no trims
+const CALIBRATION_V1_LEN: usize = 60;
+
+const MAGIC: [u8; 4] = *b"ADSC";
+
+/// The corrections applied to each channel, indexed in channel order (AIN1
+/// first) up to the number of channels on the model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Calibration {
+    pub offsets: [i32; MAX_CHANNELS],
+    pub gains: [i32; MAX_CHANNELS],
+    pub linearity: [i32; MAX_CHANNELS],
+    /// Trims of a [`crate::platform::Platform`], which the driver itself
+    /// doesn't apply
+    pub trims: [i32; MAX_CHANNELS],
+}
+
+impl Default for Calibration {
+    /// No offset, unity gain, no linearity correction, and unity trim on every
+    /// channel
+    fn default() -> Self {
+        Self {
+            offsets: [0; MAX_CHANNELS],
+            gains: [UNITY_GAIN; MAX_CHANNELS],
+            linearity: [0; MAX_CHANNELS],
+            trims: [UNITY_GAIN; MAX_CHANNELS],
+        }
+    }
+}
+
+impl Calibration {
+    pub fn to_bytes(&self) -> [u8; CALIBRATION_LEN] {
+        let mut bytes = [0; CALIBRATION_LEN];
+
+        bytes[0..4].copy_from_slice(&MAGIC);
+        bytes[4] = CALIBRATION_VERSION;
+
+        let tables = [&self.offsets, &self.gains, &self.linearity, &self.trims];
+        let values = tables.iter().flat_map(|table| table.iter());
+
+        for (chunk, value) in bytes[8..72].chunks_exact_mut(4).zip(values) {
+            chunk.copy_from_slice(&value.to_le_bytes());
+        }
+
+        let crc = crc32(&bytes[..72]);
+        bytes[72..].copy_from_slice(&crc.to_le_bytes());
+
+        bytes
+    }
+
+    /// Parses a blob written by [`Self::to_bytes`]
+    ///
+    /// Returns [`Error::InvalidCalibration`] if the bytes don't start with a
+    /// supported header or have the wrong length, and
+    /// [`Error::CorruptCalibration`] if they fail the CRC check, in which case
+    /// the device should fall back to [`Calibration::default`] (or
+    /// recalibrate) rather than weigh with garbage factors.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let len = match bytes.get(4) {
+            Some(1) => CALIBRATION_V1_LEN,
+            Some(&CALIBRATION_VERSION) => CALIBRATION_LEN,
+            _ => return Err(Error::InvalidCalibration),
+        };
+
+        if bytes.len() != len || bytes[0..4] != MAGIC {
+            return Err(Error::InvalidCalibration);
+        }
+
+        let end = len - 4;
+        let crc = u32::from_le_bytes([bytes[end], bytes[end + 1], bytes[end + 2], bytes[end + 3]]);
+
+        if crc32(&bytes[..end]) != crc {
+            return Err(Error::CorruptCalibration);
+        }
+
+        let mut values = bytes[8..end]
+            .chunks_exact(4)
+            .map(|chunk| i32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]));
+
+        let mut calibration = Self::default();
+
+        for table in [
+            &mut calibration.offsets,
+            &mut calibration.gains,
+            &mut calibration.linearity,
+            &mut calibration.trims,
+        ] {
+            for (entry, value) in table.iter_mut().zip(&mut values) {
+                *entry = value;
+            }
+        }
+
+        Ok(calibration)
+    }
+}
+
+/// Conversions between the fixed point tables and plain factors, for host
+/// tools that compute calibrations in floating point
+///
+/// Channels are given by index, as in the tables. The setters return
+/// [`Error::Overflow`] if the value isn't representable in the fixed point
+/// format, and [`Error::InvalidChannel`] if the index is out of range.
+#[cfg(feature = "std")]
+impl Calibration {
+    /// System gain of the given channel as a plain factor
+    pub fn gain_factor(&self, index: usize) -> Result<f64, Error> {
+        let gain = self.gains.get(index).ok_or(Error::InvalidChannel)?;
+
+        Ok(from_fixed(*gain, GAIN_FRACTIONAL_BITS))
+    }
+
+    /// Sets the system gain of the given channel from a plain factor
+    pub fn set_gain_factor(&mut self, index: usize, factor: f64) -> Result<(), Error> {
+        let gain = self.gains.get_mut(index).ok_or(Error::InvalidChannel)?;
+        *gain = to_fixed(factor, GAIN_FRACTIONAL_BITS)?;
+
+        Ok(())
+    }
+
+    /// Platform trim of the given channel as a plain factor
+    pub fn trim_factor(&self, index: usize) -> Result<f64, Error> {
+        let trim = self.trims.get(index).ok_or(Error::InvalidChannel)?;
+
+        Ok(from_fixed(*trim, GAIN_FRACTIONAL_BITS))
+    }
+
+    /// Sets the platform trim of the given channel from a plain factor
+    pub fn set_trim_factor(&mut self, index: usize, factor: f64) -> Result<(), Error> {
+        let trim = self.trims.get_mut(index).ok_or(Error::InvalidChannel)?;
+        *trim = to_fixed(factor, GAIN_FRACTIONAL_BITS)?;
+
+        Ok(())
+    }
+
+    /// Linearity correction of the given channel as the coefficient `k` in
+    /// `x + k * x^2` (see [`ADS123X::set_linearity_correction`])
+    pub fn linearity_coefficient(&self, index: usize) -> Result<f64, Error> {
+        let linearity = self.linearity.get(index).ok_or(Error::InvalidChannel)?;
+
+        Ok(from_fixed(*linearity, LINEARITY_FRACTIONAL_BITS))
+    }
+
+    /// Sets the linearity correction of the given channel from the coefficient
+    /// `k` in `x + k * x^2`
+    pub fn set_linearity_coefficient(&mut self, index: usize, k: f64) -> Result<(), Error> {
+        let linearity = self.linearity.get_mut(index).ok_or(Error::InvalidChannel)?;
+        *linearity = to_fixed(k, LINEARITY_FRACTIONAL_BITS)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+fn from_fixed(value: i32, fractional_bits: u32) -> f64 {
+    value as f64 / (1u64 << fractional_bits) as f64
+}
+
+#[cfg(feature = "std")]
+fn to_fixed(value: f64, fractional_bits: u32) -> Result<i32, Error> {
+    let fixed = (value * (1u64 << fractional_bits) as f64).round();
+
+    if fixed.is_nan() || fixed < i32::MIN as f64 || fixed > i32::MAX as f64 {
+        return Err(Error::Overflow);
+    }
+
+    Ok(fixed as i32)
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Returns the software offsets, system gains, and linearity corrections
+    /// of every channel, with unity trims (see
+    /// [`crate::platform::Platform::calibration`])
+    pub fn calibration(&self) -> Calibration {
+        Calibration {
+            offsets: self.offsets,
+            gains: self.gains,
+            linearity: self.linearity,
+            trims: [UNITY_GAIN; MAX_CHANNELS],
+        }
+    }
+
+    /// Replaces the software offsets, system gains, and linearity corrections
+    /// of every channel, e.g. with ones restored from non-volatile memory
+    ///
+    /// The trims are ignored, as only a [`crate::platform::Platform`] applies
+    /// them.
+    pub fn set_calibration(&mut self, calibration: &Calibration) {
+        self.offsets = calibration.offsets;
+        self.gains = calibration.gains;
+        self.linearity = calibration.linearity;
+    }
+}
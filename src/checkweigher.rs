@@ -0,0 +1,128 @@
+//! Pass/fail classification of items by weight
+//!
+//! A [`Checkweigher`] watches the stream of weights from a scale that items
+//! are placed on (or pass over) one at a time. Once the weight of an item has
+//! settled according to a [`StabilityDetector`], it is classified against the
+//! target weight and its tolerances, and the next item is only classified
+//! after the scale has been emptied again.
+
+use crate::{stats::StabilityDetector, units::Weight};
+
+/// How the weight of an item compares to the target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Verdict {
+    Under,
+    Ok,
+    Over,
+}
+
+/// The classification of one item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CheckResult {
+    pub verdict: Verdict,
+    /// The settled weight of the item
+    pub weight: Weight,
+}
+
+/// Number of items that received each [`Verdict`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CheckTotals {
+    pub under: u32,
+    pub ok: u32,
+    pub over: u32,
+}
+
+/// Classifies items as under, within, or over the tolerances around a target
+/// weight, using a window of `N` weights to check whether they have settled
+#[derive(Debug, Clone)]
+pub struct Checkweigher<const N: usize> {
+    target: Weight,
+    below: Weight,
+    above: Weight,
+    empty_below: Weight,
+    stability: StabilityDetector<N>,
+    armed: bool,
+    totals: CheckTotals,
+}
+
+impl<const N: usize> Checkweigher<N> {
+    /// Creates a checkweigher that accepts weights from `target - below` to
+    /// `target + above` inclusive, and considers a weight settled once the
+    /// last `N` weights differ by at most `tolerance`
+    ///
+    /// The scale counts as empty below half of the target weight, see
+    /// [`Self::with_empty_below`].
+    pub fn new(target: Weight, below: Weight, above: Weight, tolerance: Weight) -> Self {
+        Self {
+            target,
+            below,
+            above,
+            empty_below: target / 2,
+            stability: StabilityDetector::new(tolerance.milligrams().unsigned_abs()),
+            armed: true,
+            totals: CheckTotals::default(),
+        }
+    }
+
+    /// Sets the weight below which the scale counts as empty, so that the next
+    /// item can be classified
+    pub fn with_empty_below(mut self, empty_below: Weight) -> Self {
+        self.empty_below = empty_below;
+        self
+    }
+
+    pub fn target(&self) -> Weight {
+        self.target
+    }
+
+    /// Returns the classification a given weight would receive
+    pub fn classify(&self, weight: Weight) -> Verdict {
+        if weight < self.target - self.below {
+            Verdict::Under
+        } else if weight > self.target + self.above {
+            Verdict::Over
+        } else {
+            Verdict::Ok
+        }
+    }
+
+    /// Adds a weight and returns a result once the weight of a new item has
+    /// settled
+    pub fn push(&mut self, weight: Weight) -> Option<CheckResult> {
+        let stable = self.stability.push(weight.milligrams());
+
+        if weight < self.empty_below {
+            self.armed = true;
+            return None;
+        }
+
+        if !self.armed || !stable {
+            return None;
+        }
+
+        self.armed = false;
+
+        let verdict = self.classify(weight);
+        let total = match verdict {
+            Verdict::Under => &mut self.totals.under,
+            Verdict::Ok => &mut self.totals.ok,
+            Verdict::Over => &mut self.totals.over,
+        };
+        *total = total.wrapping_add(1);
+
+        Some(CheckResult { verdict, weight })
+    }
+
+    /// Returns the number of items classified so far
+    pub fn totals(&self) -> CheckTotals {
+        self.totals
+    }
+
+    /// Clears the totals
+    pub fn reset_totals(&mut self) {
+        self.totals = CheckTotals::default();
+    }
+}
@@ -0,0 +1,433 @@
+//! Wire protocol checks against the simulator
+//!
+//! [`check`] drives the real driver against a [`Simulator`] and compares what
+//! the chip sees with what the datasheet asks for: the number of SCLK pulses
+//! in every frame (including the 25th and 26th pulses on 24-bit models), that
+//! bits are assembled MSB first and sign extended, the conversions thrown away
+//! after a channel switch, standby entry and exit, waking up, and resetting
+//! through PWDN. Refactors of the protocol code should leave it passing, so
+//! projects that depend on the exact behaviour can run it from a test of their
+//! own, e.g. `assert_eq!(ads123x::conformance::check::<ADS1234>(), [])`.
+//!
+//! With the `embedded-hal-async` feature, every check also runs through the
+//! async API. [`TrailingPulse::Never`] isn't checked, as the simulator doesn't
+//! model the brief high pulse of DOUT that it relies on.
+
+use std::{format, string::String, vec::Vec};
+
+use crate::{
+    sim::{SimClock, SimDelay, SimDout, SimFrame, SimPin, Simulator},
+    ADSModel, Config, Error, NoHooks, Speed, TrailingPulse, ADS123X,
+};
+
+/// Data rate the checks run at
+const SPEED: Speed = Speed::Sps80;
+
+/// A protocol rule the driver broke
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Name of the check that found it
+    pub check: &'static str,
+    /// What the driver did instead
+    pub message: String,
+}
+
+impl core::fmt::Display for Violation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.check, self.message)
+    }
+}
+
+/// Runs every check against model `M`, returning the rules that were broken
+/// (with at most one violation per check)
+pub fn check<M: ADSModel>() -> Vec<Violation> {
+    let checks: [(&'static str, fn() -> Result<(), String>); 7] = [
+        ("regular read", regular_read::<M, false>),
+        ("deferred pulse", deferred_pulse::<M, false>),
+        ("offset calibration", offset_calibration::<M, false>),
+        ("settling", settling::<M, false>),
+        ("standby", standby::<M, false>),
+        ("wake", wake::<M, false>),
+        ("reset", reset::<M, false>),
+    ];
+
+    #[cfg(feature = "embedded-hal-async")]
+    let checks = checks.into_iter().chain::<[(_, fn() -> _); 7]>([
+        ("async regular read", regular_read::<M, true>),
+        ("async deferred pulse", deferred_pulse::<M, true>),
+        ("async offset calibration", offset_calibration::<M, true>),
+        ("async settling", settling::<M, true>),
+        ("async standby", standby::<M, true>),
+        ("async wake", wake::<M, true>),
+        ("async reset", reset::<M, true>),
+    ]);
+
+    checks
+        .into_iter()
+        .filter_map(|(check, run)| run().err().map(|message| Violation { check, message }))
+        .collect()
+}
+
+type SimAds<M> = ADS123X<SimDout, SimPin, SimPin, SimPin, SimPin, M, SimClock, NoHooks>;
+
+/// A driver connected to a simulator, driven through the blocking API or, with
+/// `ASYNC` set, through the async one
+struct Harness<M: ADSModel, const ASYNC: bool> {
+    ads: SimAds<M>,
+    delay: SimDelay,
+}
+
+impl<M: ADSModel, const ASYNC: bool> Harness<M, ASYNC> {
+    /// Reads from `channel` and returns the sign extended frame
+    fn read(&mut self, channel: M::Channel) -> i32 {
+        #[cfg(feature = "embedded-hal-async")]
+        if ASYNC {
+            return block_on(self.ads.read_calibrated(&mut self.delay, channel)).raw;
+        }
+
+        self.ads
+            .read_calibrated_blocking(&mut self.delay, channel)
+            .raw
+    }
+
+    fn calibrate_offset(&mut self) -> Result<u64, Error> {
+        #[cfg(feature = "embedded-hal-async")]
+        if ASYNC {
+            return block_on(self.ads.calibrate_offset(&mut self.delay));
+        }
+
+        self.ads.calibrate_offset_blocking(&mut self.delay)
+    }
+
+    fn enter_standby(&mut self) {
+        #[cfg(feature = "embedded-hal-async")]
+        if ASYNC {
+            return block_on(self.ads.enter_standby());
+        }
+
+        self.ads.enter_standby_blocking(&mut self.delay)
+    }
+
+    fn wake(&mut self) -> Result<(), Error> {
+        #[cfg(feature = "embedded-hal-async")]
+        if ASYNC {
+            return block_on(self.ads.wake(&mut self.delay));
+        }
+
+        self.ads.wake_blocking(&mut self.delay)
+    }
+
+    fn reset(&mut self) {
+        #[cfg(feature = "embedded-hal-async")]
+        if ASYNC {
+            return block_on(self.ads.reset(&mut self.delay));
+        }
+
+        self.ads.reset_blocking(&mut self.delay)
+    }
+}
+
+/// Runs a future of the async API to completion, which it reaches on the first
+/// poll as the simulated pins and delays never have to wait
+#[cfg(feature = "embedded-hal-async")]
+fn block_on<F: core::future::Future>(future: F) -> F::Output {
+    use core::task::{Context, Poll, Waker};
+
+    let mut future = core::pin::pin!(future);
+
+    match future
+        .as_mut()
+        .poll(&mut Context::from_waker(Waker::noop()))
+    {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("simulated future didn't complete on the first poll"),
+    }
+}
+
+/// Creates a simulator logging its frames and a driver connected to it
+fn setup<M: ADSModel, const ASYNC: bool>(
+    trailing_pulse: TrailingPulse,
+) -> (Simulator<M>, Harness<M, ASYNC>) {
+    let sim = Simulator::<M>::new(SPEED).with_frame_log();
+
+    for (index, &channel) in M::CHANNELS.iter().enumerate() {
+        sim.set_input(channel, input(index));
+    }
+
+    let pins = sim.pins();
+    let ads = ADS123X::new(
+        pins.dout,
+        pins.sclk,
+        pins.pwdn,
+        pins.a0,
+        pins.a1,
+        sim.clock(),
+        NoHooks,
+    )
+    .with_config(Config {
+        speed: SPEED,
+        trailing_pulse,
+        ..Config::default()
+    });
+    let delay = sim.delay();
+
+    (sim, Harness { ads, delay })
+}
+
+/// Counts the input with the given index converts to, which differ between
+/// channels so that a read from the wrong one stands out
+fn input(index: usize) -> i32 {
+    (index as i32 + 1) * 1000
+}
+
+/// Number of SCLK pulses in a frame that is followed by an extra pulse
+fn terminated<M: ADSModel>() -> u32 {
+    M::BITS + 1
+}
+
+/// Checks that every frame the driver touched saw exactly `pulses` pulses
+fn expect_pulses(frames: &[SimFrame], pulses: u32) -> Result<(), String> {
+    match frames
+        .iter()
+        .enumerate()
+        .find(|(_, frame)| frame.pulses != 0 && frame.pulses != pulses)
+    {
+        Some((index, frame)) => Err(format!(
+            "frame {index} saw {} SCLK pulses instead of {pulses}",
+            frame.pulses
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Checks that a read returned what the simulated input converts to
+fn expect_raw(raw: i32, expected: i32) -> Result<(), String> {
+    match raw == expected {
+        true => Ok(()),
+        false => Err(format!("read {raw} for an input of {expected} counts")),
+    }
+}
+
+/// Reads codes across the whole range, which are only returned intact if the
+/// bits are assembled MSB first and sign extended, with a trailing pulse after
+/// every frame
+fn regular_read<M: ADSModel, const ASYNC: bool>() -> Result<(), String> {
+    let (sim, mut ads) = setup::<M, ASYNC>(TrailingPulse::Always);
+    let channel = M::CHANNELS[0];
+    let alternating = (0x5555_5555u32 >> (33 - M::BITS)) as i32;
+
+    for counts in [
+        1,
+        -1,
+        M::FULL_SCALE_POSITIVE,
+        M::FULL_SCALE_NEGATIVE,
+        alternating,
+        -alternating,
+    ] {
+        sim.set_input(channel, counts);
+
+        // Let the chip replace any conversion of the previous input
+        sim.advance_us(SPEED.conversion_period_us() as u64);
+
+        expect_raw(ads.read(channel), counts)?;
+    }
+
+    expect_pulses(&sim.frames(), terminated::<M>())
+}
+
+/// Reads back to back with the trailing pulse deferred to the start of the
+/// next read, which has to land on the same frame
+fn deferred_pulse<M: ADSModel, const ASYNC: bool>() -> Result<(), String> {
+    let (sim, mut ads) = setup::<M, ASYNC>(TrailingPulse::Deferred);
+    let channel = M::CHANNELS[0];
+
+    for _ in 0..3 {
+        expect_raw(ads.read(channel), input(0))?;
+    }
+
+    let frames = sim.frames();
+    let (last, earlier) = frames.split_last().ok_or("no conversion was published")?;
+
+    match last.pulses == M::BITS {
+        true => expect_pulses(earlier, terminated::<M>()),
+        false => Err(format!(
+            "last frame saw {} SCLK pulses instead of {}",
+            last.pulses,
+            M::BITS
+        )),
+    }
+}
+
+/// Calibrates the offset, which takes one frame with two extra pulses and then
+/// a wait for the calibration to complete
+fn offset_calibration<M: ADSModel, const ASYNC: bool>() -> Result<(), String> {
+    let (sim, mut ads) = setup::<M, ASYNC>(TrailingPulse::Always);
+    ads.read(M::CHANNELS[0]);
+
+    let before = sim.frames().len();
+    let start_us = sim.now_us();
+    ads.calibrate_offset()
+        .map_err(|error| format!("calibration failed: {error}"))?;
+
+    let frames = &sim.frames()[before..];
+
+    match frames {
+        [calibration, completed] if calibration.pulses == M::BITS + 2 && completed.pulses == 0 => {
+            expect_raw(ads.read(M::CHANNELS[0]), input(0))
+        }
+        [calibration, ..] if calibration.pulses != M::BITS + 2 => Err(format!(
+            "calibration frame saw {} SCLK pulses instead of {}",
+            calibration.pulses,
+            M::BITS + 2
+        )),
+        _ => Err(format!(
+            "returned after {} us with {} new frames instead of waiting for the \
+             calibration to complete",
+            sim.now_us() - start_us,
+            frames.len()
+        )),
+    }
+}
+
+/// Switches between every pair of channels and counts the conversions read on
+/// the new one, which must be the settling discards plus the one returned
+fn settling<M: ADSModel, const ASYNC: bool>() -> Result<(), String> {
+    let (sim, mut ads) = setup::<M, ASYNC>(TrailingPulse::Always);
+
+    for (from_index, &from) in M::CHANNELS.iter().enumerate() {
+        for (to_index, &to) in M::CHANNELS.iter().enumerate() {
+            if from_index == to_index {
+                continue;
+            }
+
+            ads.read(from);
+
+            let before = sim.frames().len();
+            let raw = ads.read(to);
+            let frames = &sim.frames()[before..];
+
+            let expected = M::settling_discards(from, to) + 1;
+            let read = frames.iter().filter(|frame| frame.pulses != 0).count() as u32;
+
+            if read != expected {
+                return Err(format!(
+                    "read {read} conversions from channel {to_index} after channel \
+                     {from_index} instead of {expected}"
+                ));
+            }
+
+            if let Some(frame) = frames
+                .iter()
+                .find(|frame| frame.pulses != 0 && frame.channel != Some(to_index))
+            {
+                return Err(format!(
+                    "read a conversion of channel {:?} while switching to {to_index}",
+                    frame.channel
+                ));
+            }
+
+            expect_raw(raw, input(to_index))?;
+            expect_pulses(frames, terminated::<M>())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Enters standby, which holds SCLK high from the frame after DRDY on, and
+/// wakes up again with the next read
+fn standby<M: ADSModel, const ASYNC: bool>() -> Result<(), String> {
+    let (sim, mut ads) = setup::<M, ASYNC>(TrailingPulse::Always);
+    let channel = M::CHANNELS[0];
+    ads.read(channel);
+
+    let before = sim.frames().len();
+    ads.enter_standby();
+
+    // Give the chip a few periods to enter standby
+    sim.advance_us(3 * SPEED.conversion_period_us() as u64);
+
+    match &sim.frames()[before..] {
+        [frame] if frame.pulses == 1 => {}
+        [frame] => {
+            return Err(format!(
+                "frame before standby saw {} SCLK pulses instead of 1",
+                frame.pulses
+            ))
+        }
+        frames => {
+            return Err(format!(
+                "chip published {} conversions instead of entering standby",
+                frames.len()
+            ))
+        }
+    }
+
+    let before = sim.frames().len();
+    expect_single_read(&sim, &mut ads, before, "waking")
+}
+
+/// Enters standby and wakes the chip up again, after which exactly one
+/// conversion has to be published and read
+fn wake<M: ADSModel, const ASYNC: bool>() -> Result<(), String> {
+    let (sim, mut ads) = setup::<M, ASYNC>(TrailingPulse::Always);
+    ads.read(M::CHANNELS[0]);
+    ads.enter_standby();
+
+    // Give the chip a few periods to enter standby
+    sim.advance_us(3 * SPEED.conversion_period_us() as u64);
+
+    let before = sim.frames().len();
+    ads.wake()
+        .map_err(|error| format!("waking failed: {error}"))?;
+
+    match sim.frames().len() - before {
+        1 => expect_single_read(&sim, &mut ads, before, "waking"),
+        published => Err(format!(
+            "returned with {published} new conversions instead of the first one \
+             after waking"
+        )),
+    }
+}
+
+/// Resets the chip through PWDN, which must leave SCLK alone, and reads the
+/// first conversion after it
+fn reset<M: ADSModel, const ASYNC: bool>() -> Result<(), String> {
+    let (sim, mut ads) = setup::<M, ASYNC>(TrailingPulse::Always);
+    ads.read(M::CHANNELS[0]);
+
+    let before = sim.frames().len();
+    ads.reset();
+
+    if let Some(frame) = sim.frames()[before..]
+        .iter()
+        .find(|frame| frame.pulses != 0)
+    {
+        return Err(format!(
+            "reset pulsed SCLK {} times instead of leaving it low",
+            frame.pulses
+        ));
+    }
+
+    expect_single_read(&sim, &mut ads, before, "the reset")
+}
+
+/// Reads from the first channel, which has to return its input, with exactly
+/// one conversion (followed by a trailing pulse) published since the first
+/// `since` frames, e.g. after waking
+fn expect_single_read<M: ADSModel, const ASYNC: bool>(
+    sim: &Simulator<M>,
+    ads: &mut Harness<M, ASYNC>,
+    since: usize,
+    after: &str,
+) -> Result<(), String> {
+    expect_raw(ads.read(M::CHANNELS[0]), input(0))?;
+
+    match &sim.frames()[since..] {
+        [frame] => expect_pulses(&[*frame], terminated::<M>()),
+        frames => Err(format!(
+            "read {} conversions after {after} instead of 1",
+            frames.len()
+        )),
+    }
+}
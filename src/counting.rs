@@ -0,0 +1,152 @@
+//! Counting identical pieces by weight
+//!
+//! A [`PieceCounter`] learns the average weight of a piece from a reference
+//! sample with a known number of pieces, and then converts the weight of any
+//! number of pieces to a [`PieceCount`]. As the weight of a single piece is
+//! only known to within the scale's resolution divided by the size of the
+//! sample, counts of much larger quantities get less reliable, so
+//! [`PieceCounter::refine`] can adopt a larger quantity as the new reference
+//! once it has been counted with confidence. Adding pieces in several batches
+//! and refining after each one keeps the count accurate all the way up.
+//!
+//! Given the resolution of the scale (see [`PieceCounter::with_resolution`]),
+//! every count carries its uncertainty, which grows with the count and shrinks
+//! with the size of the reference sample.
+
+use crate::{units::Weight, Error};
+
+/// The number of pieces a weight corresponds to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PieceCount {
+    /// The nearest whole number of pieces
+    pub pieces: u32,
+    /// How far the weight is from exactly `pieces` pieces, in thousandths of a
+    /// piece
+    ///
+    /// A residual close to ±500 means the weight lies halfway between two
+    /// counts, e.g. because the pieces vary too much in weight or the
+    /// reference sample was too small.
+    pub residual_millipieces: i32,
+    /// Uncertainty of the count due to the resolution of the scale, in
+    /// ± thousandths of a piece
+    ///
+    /// This combines the error of the piece weight learned from the reference
+    /// sample, which is multiplied by the number of pieces counted, with the
+    /// resolution of the weighing itself. It is zero if no resolution was
+    /// given.
+    pub uncertainty_millipieces: u32,
+}
+
+impl PieceCount {
+    /// Returns whether the weight is within `max_residual_millipieces` of a
+    /// whole number of pieces even at the edges of its uncertainty
+    pub fn is_confident(&self, max_residual_millipieces: u32) -> bool {
+        self.residual_millipieces
+            .unsigned_abs()
+            .saturating_add(self.uncertainty_millipieces)
+            <= max_residual_millipieces
+    }
+}
+
+/// Converts weights to piece counts using the average weight of a reference
+/// sample
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PieceCounter {
+    reference: Option<(Weight, u32)>,
+    resolution: Weight,
+}
+
+impl PieceCounter {
+    pub const fn new() -> Self {
+        Self {
+            reference: None,
+            resolution: Weight::ZERO,
+        }
+    }
+
+    /// Sets the resolution of the weights passed in, typically the display
+    /// division of the scale (see [`crate::division::Division`]), which the
+    /// uncertainty of every count is derived from
+    pub const fn with_resolution(mut self, resolution: Weight) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    pub fn resolution(&self) -> Weight {
+        self.resolution
+    }
+
+    /// Learns the average piece weight from the weight of a sample of `pieces`
+    /// pieces
+    ///
+    /// Returns [`Error::InvalidCalibration`] if there are no pieces or the
+    /// weight isn't positive.
+    pub fn sample(&mut self, weight: Weight, pieces: u32) -> Result<(), Error> {
+        if pieces == 0 || weight <= Weight::ZERO {
+            return Err(Error::InvalidCalibration);
+        }
+
+        self.reference = Some((weight, pieces));
+
+        Ok(())
+    }
+
+    /// Returns the weight and number of pieces of the reference sample, e.g.
+    /// to persist it
+    pub fn reference(&self) -> Option<(Weight, u32)> {
+        self.reference
+    }
+
+    /// Returns the average weight of a piece (truncated to milligrams), or
+    /// `None` if no sample has been taken
+    pub fn piece_weight(&self) -> Option<Weight> {
+        let (weight, pieces) = self.reference?;
+
+        Some(weight / pieces as i64)
+    }
+
+    /// Converts a weight to the number of pieces it corresponds to, or returns
+    /// `None` if no sample has been taken
+    ///
+    /// Negative weights count as zero pieces.
+    pub fn count(&self, weight: Weight) -> Option<PieceCount> {
+        let (reference, reference_pieces) = self.reference?;
+        let reference = reference.milligrams() as i128;
+
+        // Fractional number of pieces scaled by the reference weight
+        let scaled = weight.milligrams().max(0) as i128 * reference_pieces as i128;
+        let pieces = (scaled + reference / 2) / reference;
+        let residual = (scaled - pieces * reference) * 1_000 / reference;
+
+        // The reference is off by up to one resolution step, which is spread
+        // over its pieces and so adds up over the counted ones, and the weight
+        // itself adds one more step
+        let resolution = self.resolution.milligrams().unsigned_abs() as i128;
+        let uncertainty = (pieces + reference_pieces as i128) * resolution * 1_000 / reference;
+
+        Some(PieceCount {
+            pieces: pieces.min(u32::MAX as i128) as u32,
+            residual_millipieces: residual as i32,
+            uncertainty_millipieces: uncertainty.min(u32::MAX as i128) as u32,
+        })
+    }
+
+    /// Counts the pieces in a weight and, if the count is confident (see
+    /// [`PieceCount::is_confident`]) and larger than the reference sample,
+    /// adopts the weight as the new reference
+    ///
+    /// This should only be called with settled weights, after every batch of
+    /// pieces added to the scale.
+    pub fn refine(&mut self, weight: Weight, max_residual_millipieces: u32) -> Option<PieceCount> {
+        let count = self.count(weight)?;
+        let (_, reference_pieces) = self.reference?;
+
+        if count.pieces > reference_pieces && count.is_confident(max_residual_millipieces) {
+            self.reference = Some((weight, count.pieces));
+        }
+
+        Some(count)
+    }
+}
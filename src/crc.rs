@@ -0,0 +1,101 @@
+//! Checksums and byte stuffing shared by the serialization formats
+
+/// CRC-16/CCITT-FALSE (polynomial 0x1021, initial value 0xFFFF)
+pub(crate) fn crc16(data: &[u8]) -> u16 {
+    let mut crc = 0xFFFFu16;
+
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+
+    crc
+}
+
+/// CRC-32/ISO-HDLC, the common CRC-32 used by zlib and Ethernet (reflected
+/// polynomial 0xEDB88320, initial value and final XOR 0xFFFFFFFF)
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+
+    for &byte in data {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+/// Encodes `input` with consistent overhead byte stuffing (COBS) so that the
+/// output contains no zero bytes, and returns the number of bytes written
+///
+/// The output must be at least `input.len() + input.len() / 254 + 1` bytes
+/// long. The trailing zero delimiter is not written.
+pub(crate) fn cobs_encode(input: &[u8], output: &mut [u8]) -> usize {
+    let mut code_index = 0;
+    let mut written = 1;
+    let mut code = 1u8;
+
+    for &byte in input {
+        if byte != 0 {
+            output[written] = byte;
+            written += 1;
+            code += 1;
+        }
+
+        if byte == 0 || code == 0xFF {
+            output[code_index] = code;
+            code_index = written;
+            written += 1;
+            code = 1;
+        }
+    }
+
+    output[code_index] = code;
+    written
+}
+
+/// Decodes a COBS encoded block (without its trailing zero delimiter) and
+/// returns the number of bytes written, or `None` if the block is malformed or
+/// doesn't fit in the output
+#[cfg(feature = "std")]
+pub(crate) fn cobs_decode(input: &[u8], output: &mut [u8]) -> Option<usize> {
+    let mut read = 0;
+    let mut written = 0;
+
+    while read < input.len() {
+        let code = input[read] as usize;
+
+        if code == 0 || read + code > input.len() {
+            return None;
+        }
+
+        read += 1;
+
+        for _ in 1..code {
+            *output.get_mut(written)? = input[read];
+            read += 1;
+            written += 1;
+        }
+
+        if code != 0xFF && read != input.len() {
+            *output.get_mut(written)? = 0;
+            written += 1;
+        }
+    }
+
+    Some(written)
+}
@@ -0,0 +1,92 @@
+//! Combining separate delay providers for short and long waits
+//!
+//! The driver waits for two very different kinds of durations: the SCLK
+//! pulses of a few hundred nanoseconds, and the microsecond to millisecond
+//! waits of a reset, a channel change, or polling DRDY. A single [`DelayNs`]
+//! is usually poor at one of the extremes: a timer-based delay has too much
+//! overhead for the pulses, while a busy loop wastes power (and can't yield
+//! in async code) during the long waits. A [`SplitDelay`] hands each wait to
+//! whichever of two delays suits it.
+
+use embedded_hal::delay::DelayNs;
+
+/// A delay that performs waits shorter than a threshold with a `fine` delay
+/// (e.g. a busy loop or cycle counter) and all others with a `coarse` one
+/// (e.g. a hardware timer)
+///
+/// In async code the coarse delay is awaited while the fine delay still
+/// blocks, which is fine for the nanosecond-scale SCLK delays.
+pub struct SplitDelay<C, F> {
+    coarse: C,
+    fine: F,
+    threshold_ns: u32,
+}
+
+impl<C, F> SplitDelay<C, F> {
+    /// Combines the two delays, using the fine delay for waits below 1 us
+    pub const fn new(coarse: C, fine: F) -> Self {
+        Self {
+            coarse,
+            fine,
+            threshold_ns: 1_000,
+        }
+    }
+
+    /// Sets the shortest wait that is handed to the coarse delay
+    pub const fn with_threshold_ns(mut self, threshold_ns: u32) -> Self {
+        self.threshold_ns = threshold_ns;
+        self
+    }
+
+    /// Releases the two delays
+    pub fn free(self) -> (C, F) {
+        (self.coarse, self.fine)
+    }
+}
+
+impl<C: DelayNs, F: DelayNs> DelayNs for SplitDelay<C, F> {
+    fn delay_ns(&mut self, ns: u32) {
+        if ns < self.threshold_ns {
+            self.fine.delay_ns(ns);
+        } else {
+            self.coarse.delay_ns(ns);
+        }
+    }
+
+    fn delay_us(&mut self, us: u32) {
+        if us.saturating_mul(1_000) < self.threshold_ns {
+            self.fine.delay_us(us);
+        } else {
+            self.coarse.delay_us(us);
+        }
+    }
+
+    fn delay_ms(&mut self, ms: u32) {
+        self.coarse.delay_ms(ms);
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<C: embedded_hal_async::delay::DelayNs, F: DelayNs> embedded_hal_async::delay::DelayNs
+    for SplitDelay<C, F>
+{
+    async fn delay_ns(&mut self, ns: u32) {
+        if ns < self.threshold_ns {
+            self.fine.delay_ns(ns);
+        } else {
+            self.coarse.delay_ns(ns).await;
+        }
+    }
+
+    async fn delay_us(&mut self, us: u32) {
+        if us.saturating_mul(1_000) < self.threshold_ns {
+            self.fine.delay_us(us);
+        } else {
+            self.coarse.delay_us(us).await;
+        }
+    }
+
+    async fn delay_ms(&mut self, ms: u32) {
+        self.coarse.delay_ms(ms).await;
+    }
+}
@@ -0,0 +1,115 @@
+//! Rounding of weights to scale divisions
+//!
+//! Legal-for-trade scales (OIML R 76, NIST Handbook 44) display weights in
+//! steps of the display division `d`, and are verified in steps of the
+//! verification division `e`, which is either equal to `d` or a larger
+//! decimal multiple of it. A [`Division`] rounds weights to the display
+//! division and applies the usual indication limits around them:
+//!
+//! - Weights within a quarter of `e` of zero are the center of zero, which
+//!   scales indicate with a dedicated annunciator.
+//! - No weight is indicated more than 9 `e` above the maximum capacity (if one
+//!   is configured), or more than 20 `e` below zero, so that overloads and
+//!   underloads are blanked rather than shown as misleading values.
+
+use crate::units::Weight;
+
+/// What a scale should show for a weight
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Indication {
+    /// The weight rounded to the display division
+    Value(Weight),
+    /// The weight is too far below zero to be indicated
+    Underload,
+    /// The weight is too far above the maximum capacity to be indicated
+    Overload,
+}
+
+/// The display and verification divisions of a scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Division {
+    d: Weight,
+    e: Weight,
+    max: Option<Weight>,
+}
+
+impl Division {
+    /// Creates divisions with a display division of `d` (at least 1 mg) and a
+    /// verification division of the same size, without a maximum capacity
+    pub const fn new(d: Weight) -> Self {
+        let d = if d.milligrams() < 1 {
+            Weight::from_milligrams(1)
+        } else {
+            d
+        };
+
+        Self { d, e: d, max: None }
+    }
+
+    /// Sets the verification division, which is never smaller than the display
+    /// division
+    pub const fn with_verification_division(mut self, e: Weight) -> Self {
+        self.e = if e.milligrams() < self.d.milligrams() {
+            self.d
+        } else {
+            e
+        };
+        self
+    }
+
+    /// Sets the maximum capacity, above which weights of more than 9 `e` are
+    /// blanked
+    pub const fn with_max_capacity(mut self, max: Weight) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub const fn display_division(&self) -> Weight {
+        self.d
+    }
+
+    pub const fn verification_division(&self) -> Weight {
+        self.e
+    }
+
+    /// Rounds a weight to the nearest multiple of the display division, with
+    /// halfway cases rounded away from zero
+    pub const fn round(&self, weight: Weight) -> Weight {
+        let d = self.d.milligrams();
+        let mg = weight.milligrams();
+
+        let half = d / 2;
+        let steps = if mg >= 0 {
+            mg.saturating_add(half) / d
+        } else {
+            mg.saturating_sub(half) / d
+        };
+
+        Weight::from_milligrams(steps.saturating_mul(d))
+    }
+
+    /// Returns whether a weight is within a quarter of the verification
+    /// division of zero
+    pub const fn is_center_of_zero(&self, weight: Weight) -> bool {
+        weight.milligrams().unsigned_abs() * 4 <= self.e.milligrams().unsigned_abs()
+    }
+
+    /// Returns what a scale should show for a weight
+    pub const fn indicate(&self, weight: Weight) -> Indication {
+        let e = self.e.milligrams();
+
+        if weight.milligrams() < e.saturating_mul(-20) {
+            return Indication::Underload;
+        }
+
+        if let Some(max) = self.max {
+            if weight.milligrams() > max.milligrams().saturating_add(e.saturating_mul(9)) {
+                return Indication::Overload;
+            }
+        }
+
+        Indication::Value(self.round(weight))
+    }
+}
@@ -0,0 +1,173 @@
+//! Long-term zero drift of a load cell
+//!
+//! A [`DriftTracker`] records a baseline reading, typically right after a tare,
+//! and compares later readings of the unloaded cell against it, timestamped
+//! with a [`Clock`]. A cell whose zero keeps wandering off over hours or days
+//! is often about to fail (moisture in the gauges, a creeping bond, or a
+//! damaged cable), so logging the drift supports replacing it before it
+//! ruins measurements.
+//!
+//! Readings should be averaged (e.g. with
+//! [`crate::ADS123X::read_averaged_blocking`]) and taken with nothing on the
+//! cell, as the tracker can't tell a load from drift.
+
+use crate::{
+    scale::Span,
+    units::{saturate, Force, Weight},
+    Clock,
+};
+
+/// Microseconds in an hour
+const US_PER_HOUR: i128 = 3_600_000_000;
+
+/// A reading and the time it was taken
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DriftSample {
+    /// Time of the reading in microseconds, as measured by the tracker's
+    /// [`Clock`]
+    pub timestamp_us: u64,
+    /// Reading in corrected counts
+    pub counts: i32,
+}
+
+/// Tracks how far readings have drifted from a baseline over time
+#[derive(Debug, Clone)]
+pub struct DriftTracker<C: Clock> {
+    clock: C,
+    baseline: Option<DriftSample>,
+    latest: Option<DriftSample>,
+    max_drift: i64,
+}
+
+impl<C: Clock> DriftTracker<C> {
+    /// Creates a tracker without a baseline that timestamps readings with the
+    /// given clock
+    pub const fn new(clock: C) -> Self {
+        Self {
+            clock,
+            baseline: None,
+            latest: None,
+            max_drift: 0,
+        }
+    }
+
+    /// Releases the clock
+    pub fn free(self) -> C {
+        self.clock
+    }
+
+    /// Starts over from a new baseline, e.g. the reading a tare was taken at
+    pub fn set_baseline(&mut self, counts: i32) {
+        let sample = DriftSample {
+            timestamp_us: self.clock.now_us(),
+            counts,
+        };
+
+        self.restore_baseline(sample);
+    }
+
+    /// Starts over from a baseline taken earlier, e.g. one that was persisted
+    /// across a reboot (which only works with a clock that keeps counting)
+    pub fn restore_baseline(&mut self, baseline: DriftSample) {
+        self.baseline = Some(baseline);
+        self.latest = None;
+        self.max_drift = 0;
+    }
+
+    pub fn baseline(&self) -> Option<DriftSample> {
+        self.baseline
+    }
+
+    /// Records a reading of the unloaded cell, which becomes the baseline if
+    /// there isn't one yet
+    pub fn push(&mut self, counts: i32) {
+        let sample = DriftSample {
+            timestamp_us: self.clock.now_us(),
+            counts,
+        };
+
+        let Some(baseline) = self.baseline else {
+            self.restore_baseline(sample);
+            return;
+        };
+
+        let drift = counts as i64 - baseline.counts as i64;
+
+        if drift.abs() > self.max_drift.abs() {
+            self.max_drift = drift;
+        }
+
+        self.latest = Some(sample);
+    }
+
+    /// Returns the most recent reading since the baseline
+    pub fn latest(&self) -> Option<DriftSample> {
+        self.latest
+    }
+
+    /// Returns how many counts the most recent reading is away from the
+    /// baseline, or `None` before the first reading since the baseline
+    pub fn drift_counts(&self) -> Option<i64> {
+        let (baseline, latest) = (self.baseline?, self.latest?);
+
+        Some(latest.counts as i64 - baseline.counts as i64)
+    }
+
+    /// Returns the drift of the reading furthest away from the baseline, which
+    /// catches excursions that have since recovered
+    pub fn max_drift_counts(&self) -> i64 {
+        self.max_drift
+    }
+
+    /// Returns the time between the baseline and the most recent reading in
+    /// microseconds
+    pub fn elapsed_us(&self) -> Option<u64> {
+        let (baseline, latest) = (self.baseline?, self.latest?);
+
+        Some(latest.timestamp_us.saturating_sub(baseline.timestamp_us))
+    }
+
+    /// Returns the time between the baseline and the most recent reading
+    #[cfg(feature = "fugit")]
+    pub fn elapsed(&self) -> Option<fugit::MicrosDurationU64> {
+        self.elapsed_us().map(fugit::MicrosDurationU64::from_ticks)
+    }
+
+    /// Returns the average drift since the baseline in thousandths of a count
+    /// per hour, or `None` until some time has passed
+    pub fn drift_rate_millicounts_per_hour(&self) -> Option<i64> {
+        let drift = self.drift_counts()? as i128;
+        let elapsed_us = self.elapsed_us().filter(|&us| us > 0)? as i128;
+
+        Some(saturate(drift * 1_000 * US_PER_HOUR / elapsed_us))
+    }
+
+    /// Converts the drift to a weight using a span as returned by
+    /// [`crate::scale::Scale::span`], or returns `None` for a span of zero
+    /// counts
+    pub fn drift_weight(&self, (reference, span_counts): (Span, i32)) -> Option<Weight> {
+        if span_counts == 0 {
+            return None;
+        }
+
+        let drift = self.drift_counts()? as i128;
+        let milligrams = drift * reference.weight().milligrams() as i128 / span_counts as i128;
+
+        Some(Weight::from_milligrams(saturate(milligrams)))
+    }
+
+    /// Converts the drift to a force using a span as returned by
+    /// [`crate::scale::Scale::span`], or returns `None` for a span of zero
+    /// counts
+    pub fn drift_force(&self, (reference, span_counts): (Span, i32)) -> Option<Force> {
+        if span_counts == 0 {
+            return None;
+        }
+
+        let drift = self.drift_counts()? as i128;
+        let millinewtons = drift * reference.force().millinewtons() as i128 / span_counts as i128;
+
+        Some(Force::from_millinewtons(saturate(millinewtons)))
+    }
+}
@@ -0,0 +1,45 @@
+//! Integration with [`embassy_sync`]
+
+use embassy_sync::{blocking_mutex::raw::RawMutex, watch::Watch};
+use embedded_hal::digital::{InputPin, OutputPin, StatefulOutputPin};
+use embedded_hal_async::{delay::DelayNs, digital::Wait};
+
+use crate::{ADSModel, AdsHooks, Clock, TimingProfile, ADS123X};
+
+/// Continuously reads from the given channel and publishes each value to a
+/// [`Watch`], so that UI, logging, and control tasks can each observe the
+/// latest reading through their own receivers
+///
+/// Every corrected reading is passed through `filter` first, and only the
+/// values it returns are published. This allows decimating filters such as
+/// [`crate::filters::Cic`] to be used directly, while per-sample filters can be
+/// wrapped as e.g. `|x| Some(hampel.filter(x))`.
+pub async fn publish_to_watch<DOUT, SCLK, PWDN, A0, A1, M, C, H, T, RM, const N: usize>(
+    ads: &mut ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>,
+    delay: &mut impl DelayNs,
+    channel: M::Channel,
+    watch: &Watch<RM, i32, N>,
+    mut filter: impl FnMut(i32) -> Option<i32>,
+) -> !
+where
+    DOUT: InputPin + Wait,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+    RM: RawMutex,
+{
+    let sender = watch.sender();
+
+    loop {
+        let value = ads.read_calibrated(delay, channel).await.corrected;
+
+        if let Some(value) = filter(value) {
+            sender.send(value);
+        }
+    }
+}
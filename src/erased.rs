@@ -0,0 +1,248 @@
+//! A driver over type-erased pins
+//!
+//! Every combination of pin types the driver is used with instantiates all of
+//! its code again. Projects that drive several converters wired to different
+//! pins (which most HALs give distinct types) can instead borrow the pins as
+//! trait objects and use a single [`ErasedAds`] type for all of them, at the
+//! cost of a dynamic call for every pin access.
+//!
+//! With the `alloc` feature, the pins can also be owned by the driver as boxed
+//! trait objects in a [`BoxedAds`].
+//!
+//! Pin errors are converted to their [`ErrorKind`]. Only the blocking API is
+//! available, as waiting for DOUT asynchronously can't be expressed through a
+//! trait object.
+
+use embedded_hal::digital::{
+    Error as _, ErrorKind, ErrorType, InputPin, OutputPin, StatefulOutputPin,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use crate::{ADSModel, DefaultTiming, NoClock, NoHooks, ADS123X};
+
+/// An [`InputPin`] that can be used as a trait object
+pub trait DynInputPin {
+    fn is_high(&mut self) -> Result<bool, ErrorKind>;
+    fn is_low(&mut self) -> Result<bool, ErrorKind>;
+}
+
+impl<P: InputPin> DynInputPin for P {
+    fn is_high(&mut self) -> Result<bool, ErrorKind> {
+        InputPin::is_high(self).map_err(|e| e.kind())
+    }
+
+    fn is_low(&mut self) -> Result<bool, ErrorKind> {
+        InputPin::is_low(self).map_err(|e| e.kind())
+    }
+}
+
+impl ErrorType for dyn DynInputPin + '_ {
+    type Error = ErrorKind;
+}
+
+impl InputPin for dyn DynInputPin + '_ {
+    fn is_high(&mut self) -> Result<bool, ErrorKind> {
+        DynInputPin::is_high(self)
+    }
+
+    fn is_low(&mut self) -> Result<bool, ErrorKind> {
+        DynInputPin::is_low(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ErrorType for Box<dyn DynInputPin + '_> {
+    type Error = ErrorKind;
+}
+
+#[cfg(feature = "alloc")]
+impl InputPin for Box<dyn DynInputPin + '_> {
+    fn is_high(&mut self) -> Result<bool, ErrorKind> {
+        DynInputPin::is_high(&mut **self)
+    }
+
+    fn is_low(&mut self) -> Result<bool, ErrorKind> {
+        DynInputPin::is_low(&mut **self)
+    }
+}
+
+/// An [`OutputPin`] that can be used as a trait object
+pub trait DynOutputPin {
+    fn set_low(&mut self) -> Result<(), ErrorKind>;
+    fn set_high(&mut self) -> Result<(), ErrorKind>;
+}
+
+impl<P: OutputPin> DynOutputPin for P {
+    fn set_low(&mut self) -> Result<(), ErrorKind> {
+        OutputPin::set_low(self).map_err(|e| e.kind())
+    }
+
+    fn set_high(&mut self) -> Result<(), ErrorKind> {
+        OutputPin::set_high(self).map_err(|e| e.kind())
+    }
+}
+
+impl ErrorType for dyn DynOutputPin + '_ {
+    type Error = ErrorKind;
+}
+
+impl OutputPin for dyn DynOutputPin + '_ {
+    fn set_low(&mut self) -> Result<(), ErrorKind> {
+        DynOutputPin::set_low(self)
+    }
+
+    fn set_high(&mut self) -> Result<(), ErrorKind> {
+        DynOutputPin::set_high(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ErrorType for Box<dyn DynOutputPin + '_> {
+    type Error = ErrorKind;
+}
+
+#[cfg(feature = "alloc")]
+impl OutputPin for Box<dyn DynOutputPin + '_> {
+    fn set_low(&mut self) -> Result<(), ErrorKind> {
+        DynOutputPin::set_low(&mut **self)
+    }
+
+    fn set_high(&mut self) -> Result<(), ErrorKind> {
+        DynOutputPin::set_high(&mut **self)
+    }
+}
+
+/// A [`StatefulOutputPin`] that can be used as a trait object
+///
+/// [`crate::NoPin`] implements this for channel select pins that aren't
+/// connected.
+pub trait DynStatefulOutputPin: DynOutputPin {
+    fn is_set_high(&mut self) -> Result<bool, ErrorKind>;
+    fn is_set_low(&mut self) -> Result<bool, ErrorKind>;
+}
+
+impl<P: StatefulOutputPin> DynStatefulOutputPin for P {
+    fn is_set_high(&mut self) -> Result<bool, ErrorKind> {
+        StatefulOutputPin::is_set_high(self).map_err(|e| e.kind())
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, ErrorKind> {
+        StatefulOutputPin::is_set_low(self).map_err(|e| e.kind())
+    }
+}
+
+impl ErrorType for dyn DynStatefulOutputPin + '_ {
+    type Error = ErrorKind;
+}
+
+impl OutputPin for dyn DynStatefulOutputPin + '_ {
+    fn set_low(&mut self) -> Result<(), ErrorKind> {
+        DynOutputPin::set_low(self)
+    }
+
+    fn set_high(&mut self) -> Result<(), ErrorKind> {
+        DynOutputPin::set_high(self)
+    }
+}
+
+impl StatefulOutputPin for dyn DynStatefulOutputPin + '_ {
+    fn is_set_high(&mut self) -> Result<bool, ErrorKind> {
+        DynStatefulOutputPin::is_set_high(self)
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, ErrorKind> {
+        DynStatefulOutputPin::is_set_low(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ErrorType for Box<dyn DynStatefulOutputPin + '_> {
+    type Error = ErrorKind;
+}
+
+#[cfg(feature = "alloc")]
+impl OutputPin for Box<dyn DynStatefulOutputPin + '_> {
+    fn set_low(&mut self) -> Result<(), ErrorKind> {
+        DynOutputPin::set_low(&mut **self)
+    }
+
+    fn set_high(&mut self) -> Result<(), ErrorKind> {
+        DynOutputPin::set_high(&mut **self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl StatefulOutputPin for Box<dyn DynStatefulOutputPin + '_> {
+    fn is_set_high(&mut self) -> Result<bool, ErrorKind> {
+        DynStatefulOutputPin::is_set_high(&mut **self)
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, ErrorKind> {
+        DynStatefulOutputPin::is_set_low(&mut **self)
+    }
+}
+
+/// A driver whose pins are borrowed as trait objects, so that a single
+/// instantiation serves every combination of pin types
+pub type ErasedAds<'a, M, C = NoClock, H = NoHooks, T = DefaultTiming> = ADS123X<
+    &'a mut dyn DynInputPin,
+    &'a mut dyn DynOutputPin,
+    &'a mut dyn DynOutputPin,
+    &'a mut dyn DynStatefulOutputPin,
+    &'a mut dyn DynStatefulOutputPin,
+    M,
+    C,
+    H,
+    T,
+>;
+
+impl<'a, M: ADSModel> ErasedAds<'a, M> {
+    /// Creates a driver for model `M` over borrowed pins of any type
+    ///
+    /// The pins coerce to trait objects automatically, so they can be passed
+    /// as e.g. `&mut dout`. Unconnected channel select pins can be passed as a
+    /// borrowed [`crate::NoPin`].
+    pub fn from_pins(
+        dout: &'a mut dyn DynInputPin,
+        sclk: &'a mut dyn DynOutputPin,
+        pwdn: &'a mut dyn DynOutputPin,
+        a0: &'a mut dyn DynStatefulOutputPin,
+        a1: &'a mut dyn DynStatefulOutputPin,
+    ) -> ErasedAds<'a, M> {
+        ADS123X::new(dout, sclk, pwdn, a0, a1, NoClock, NoHooks)
+    }
+}
+
+/// A driver that owns its pins as boxed trait objects, so that a single
+/// instantiation serves every combination of pin types
+#[cfg(feature = "alloc")]
+pub type BoxedAds<M, C = NoClock, H = NoHooks, T = DefaultTiming> = ADS123X<
+    Box<dyn DynInputPin>,
+    Box<dyn DynOutputPin>,
+    Box<dyn DynOutputPin>,
+    Box<dyn DynStatefulOutputPin>,
+    Box<dyn DynStatefulOutputPin>,
+    M,
+    C,
+    H,
+    T,
+>;
+
+#[cfg(feature = "alloc")]
+impl<M: ADSModel> BoxedAds<M> {
+    /// Creates a driver for model `M` over boxed pins of any type
+    ///
+    /// Unlike an [`ErasedAds`], the driver can be moved around freely (e.g.
+    /// into a `static`), as it doesn't borrow the pins.
+    pub fn from_pins(
+        dout: Box<dyn DynInputPin>,
+        sclk: Box<dyn DynOutputPin>,
+        pwdn: Box<dyn DynOutputPin>,
+        a0: Box<dyn DynStatefulOutputPin>,
+        a1: Box<dyn DynStatefulOutputPin>,
+    ) -> Self {
+        ADS123X::new(dout, sclk, pwdn, a0, a1, NoClock, NoHooks)
+    }
+}
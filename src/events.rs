@@ -0,0 +1,117 @@
+//! Detection of items being placed on or removed from a scale
+//!
+//! A [`WeighEventDetector`] tracks the last settled weight. Once the weight
+//! departs from it by at least a minimum step, the detector waits for the
+//! weight to settle again according to a [`StabilityDetector`] and reports the
+//! difference as a [`WeighEvent`]. Settling back near the previous weight (e.g.
+//! an item being lifted and put back) reports nothing, and small settled
+//! changes are taken as the new reference without an event so that slow drift
+//! doesn't accumulate into a spurious one.
+
+use crate::{stats::StabilityDetector, units::Weight};
+
+/// An item being placed on or removed from the scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WeighEvent {
+    /// The settled weight increased by `delta` to `total`
+    Placed { delta: Weight, total: Weight },
+    /// The settled weight decreased by `delta` to `total`
+    Removed { delta: Weight, total: Weight },
+}
+
+impl WeighEvent {
+    /// Settled change in weight, which is negative for removals
+    pub fn signed_delta(&self) -> Weight {
+        match *self {
+            WeighEvent::Placed { delta, .. } => delta,
+            WeighEvent::Removed { delta, .. } => -delta,
+        }
+    }
+}
+
+/// Detects settled step changes of at least a minimum weight, using a window
+/// of `N` weights to check whether they have settled
+#[derive(Debug, Clone)]
+pub struct WeighEventDetector<const N: usize> {
+    min_step: Weight,
+    stability: StabilityDetector<N>,
+    settled: Option<Weight>,
+    moving: bool,
+}
+
+impl<const N: usize> WeighEventDetector<N> {
+    /// Creates a detector that reports settled changes of at least `min_step`,
+    /// and considers a weight settled once the last `N` weights differ by at
+    /// most `tolerance`
+    ///
+    /// The first settled weight is taken as the reference without an event.
+    pub fn new(min_step: Weight, tolerance: Weight) -> Self {
+        Self {
+            min_step: min_step.abs(),
+            stability: StabilityDetector::new(tolerance.milligrams().unsigned_abs()),
+            settled: None,
+            moving: false,
+        }
+    }
+
+    /// Last settled weight, or `None` if the weight hasn't settled yet
+    pub fn settled(&self) -> Option<Weight> {
+        self.settled
+    }
+
+    /// Returns whether the weight has departed from the settled one and not
+    /// settled again yet
+    pub fn is_moving(&self) -> bool {
+        self.moving
+    }
+
+    /// Adds a weight and returns an event once a step change has settled
+    pub fn push(&mut self, weight: Weight) -> Option<WeighEvent> {
+        let stable = self.stability.push(weight.milligrams());
+
+        let Some(settled) = self.settled else {
+            if stable {
+                self.settled = Some(weight);
+            }
+
+            return None;
+        };
+
+        let delta = weight - settled;
+
+        if delta.abs() >= self.min_step {
+            self.moving = true;
+        }
+
+        if !stable {
+            return None;
+        }
+
+        self.settled = Some(weight);
+
+        if !core::mem::replace(&mut self.moving, false) || delta.abs() < self.min_step {
+            return None;
+        }
+
+        Some(if delta > Weight::ZERO {
+            WeighEvent::Placed {
+                delta,
+                total: weight,
+            }
+        } else {
+            WeighEvent::Removed {
+                delta: delta.abs(),
+                total: weight,
+            }
+        })
+    }
+
+    /// Forgets the settled weight, so that the next one is taken as the
+    /// reference without an event (e.g. after taring)
+    pub fn reset(&mut self) {
+        self.stability.reset();
+        self.settled = None;
+        self.moving = false;
+    }
+}
@@ -0,0 +1,474 @@
+//! C-callable API for mixed C and Rust firmware
+//!
+//! The application supplies an [`AdsCallbacks`] table of functions that drive
+//! the pins and delay, and storage for an opaque [`AdsHandle`] (of at least
+//! [`ads123x_handle_size`] bytes, aligned to [`ads123x_handle_align`]). All
+//! other functions take the pointer returned by [`ads123x_init`], and return
+//! one of the `ADS123X_*` status codes, with any result written through an out
+//! pointer. `ads123x.h` in the root of the crate declares the API for C.
+//!
+//! Channels are passed as their index in the model's [`ADSModel::CHANNELS`],
+//! e.g. 0 for AIN1 and 2 for TEMP on the ADS1232.
+
+use core::ffi::c_void;
+use core::mem::{align_of, size_of};
+
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{ErrorType, InputPin, OutputPin, StatefulOutputPin},
+};
+
+#[cfg(feature = "ads1232")]
+use crate::ADS1232;
+#[cfg(feature = "ads1234")]
+use crate::ADS1234;
+use crate::{ADSModel, Clock, Error, NoHooks, ADS123X};
+use crate::{ADS1130, ADS1131};
+
+/// The call succeeded
+pub const ADS123X_OK: i32 = 0;
+/// A pointer was null, the storage was too small or misaligned, or the model
+/// isn't supported
+pub const ADS123X_ERR_INVALID_ARGUMENT: i32 = -1;
+/// See [`Error::InvalidChannel`]
+pub const ADS123X_ERR_INVALID_CHANNEL: i32 = -2;
+/// See [`Error::WakeTimeout`]
+pub const ADS123X_ERR_WAKE_TIMEOUT: i32 = -3;
+/// See [`Error::Unstable`]
+pub const ADS123X_ERR_UNSTABLE: i32 = -4;
+/// See [`Error::InvalidCalibration`]
+pub const ADS123X_ERR_INVALID_CALIBRATION: i32 = -5;
+/// Any other error
+pub const ADS123X_ERR_OTHER: i32 = -6;
+/// See [`Error::Overflow`]
+pub const ADS123X_ERR_OVERFLOW: i32 = -7;
+/// See [`Error::CalibrationTimeout`]
+pub const ADS123X_ERR_CALIBRATION_TIMEOUT: i32 = -8;
+
+/// Functions through which the driver accesses the hardware, each of which is
+/// passed `context`
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct AdsCallbacks {
+    pub context: *mut c_void,
+    /// Returns the level of DOUT
+    pub read_dout: extern "C" fn(*mut c_void) -> bool,
+    /// Sets the level of SCLK
+    pub write_sclk: extern "C" fn(*mut c_void, bool),
+    /// Sets the level of PWDN
+    pub write_pwdn: extern "C" fn(*mut c_void, bool),
+    /// Sets the level of A0, or null if it isn't connected
+    pub write_a0: Option<extern "C" fn(*mut c_void, bool)>,
+    /// Sets the level of A1 (TEMP on the ADS1232), or null if it isn't
+    /// connected
+    pub write_a1: Option<extern "C" fn(*mut c_void, bool)>,
+    /// Waits for at least the given number of nanoseconds
+    pub delay_ns: extern "C" fn(*mut c_void, u32),
+    /// Returns a monotonic timestamp in microseconds, or null if there is no
+    /// clock
+    pub now_us: Option<extern "C" fn(*mut c_void) -> u64>,
+}
+
+/// DOUT read through [`AdsCallbacks::read_dout`]
+struct CallbackInput(AdsCallbacks);
+
+impl ErrorType for CallbackInput {
+    type Error = core::convert::Infallible;
+}
+
+impl InputPin for CallbackInput {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok((self.0.read_dout)(self.0.context))
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(!(self.0.read_dout)(self.0.context))
+    }
+}
+
+/// An output written through one of the callbacks, which remembers its level
+/// so it can be read back
+struct CallbackOutput {
+    context: *mut c_void,
+    write: Option<extern "C" fn(*mut c_void, bool)>,
+    high: bool,
+}
+
+impl CallbackOutput {
+    fn new(context: *mut c_void, write: Option<extern "C" fn(*mut c_void, bool)>) -> Self {
+        Self {
+            context,
+            write,
+            high: false,
+        }
+    }
+}
+
+impl ErrorType for CallbackOutput {
+    type Error = core::convert::Infallible;
+}
+
+impl OutputPin for CallbackOutput {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.high = false;
+        if let Some(write) = self.write {
+            write(self.context, false);
+        }
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.high = true;
+        if let Some(write) = self.write {
+            write(self.context, true);
+        }
+        Ok(())
+    }
+}
+
+impl StatefulOutputPin for CallbackOutput {
+    fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.high)
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.high)
+    }
+}
+
+/// Clock read through [`AdsCallbacks::now_us`]
+struct CallbackClock(AdsCallbacks);
+
+impl Clock for CallbackClock {
+    fn now_us(&mut self) -> u64 {
+        self.0.now_us.map_or(0, |now_us| now_us(self.0.context))
+    }
+}
+
+/// Delay through [`AdsCallbacks::delay_ns`]
+struct CallbackDelay(AdsCallbacks);
+
+impl DelayNs for CallbackDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        (self.0.delay_ns)(self.0.context, ns)
+    }
+}
+
+type CallbackAds<M> = ADS123X<
+    CallbackInput,
+    CallbackOutput,
+    CallbackOutput,
+    CallbackOutput,
+    CallbackOutput,
+    M,
+    CallbackClock,
+    NoHooks,
+>;
+
+enum Driver {
+    #[cfg(feature = "ads1232")]
+    Ads1232(CallbackAds<ADS1232>),
+    #[cfg(feature = "ads1234")]
+    Ads1234(CallbackAds<ADS1234>),
+    Ads1130(CallbackAds<ADS1130>),
+    Ads1131(CallbackAds<ADS1131>),
+}
+
+/// A driver instance, which is opaque to C
+pub struct AdsHandle {
+    driver: Driver,
+    delay: CallbackDelay,
+}
+
+/// Runs `$body` with `$ads` bound to the driver of whichever model the handle
+/// was initialized for
+macro_rules! with_driver {
+    ($handle:expr, $ads:ident => $body:expr) => {
+        match &mut $handle.driver {
+            #[cfg(feature = "ads1232")]
+            Driver::Ads1232($ads) => $body,
+            #[cfg(feature = "ads1234")]
+            Driver::Ads1234($ads) => $body,
+            Driver::Ads1130($ads) => $body,
+            Driver::Ads1131($ads) => $body,
+        }
+    };
+}
+
+/// Creates a driver for model `M` whose pins and clock go through the
+/// callbacks
+fn driver<M: ADSModel>(callbacks: AdsCallbacks) -> CallbackAds<M> {
+    let context = callbacks.context;
+
+    ADS123X::new(
+        CallbackInput(callbacks),
+        CallbackOutput::new(context, Some(callbacks.write_sclk)),
+        CallbackOutput::new(context, Some(callbacks.write_pwdn)),
+        CallbackOutput::new(context, callbacks.write_a0),
+        CallbackOutput::new(context, callbacks.write_a1),
+        CallbackClock(callbacks),
+        NoHooks,
+    )
+}
+
+/// Returns the channel of model `M` with the given index
+fn channel<M: ADSModel>(index: u8) -> Result<M::Channel, Error> {
+    M::CHANNELS
+        .get(index as usize)
+        .copied()
+        .ok_or(Error::InvalidChannel)
+}
+
+fn read<M: ADSModel>(
+    ads: &mut CallbackAds<M>,
+    delay: &mut CallbackDelay,
+    index: u8,
+) -> Result<i32, Error> {
+    let channel = channel::<M>(index)?;
+
+    Ok(ads.read_calibrated_blocking(delay, channel).corrected)
+}
+
+fn tare<M: ADSModel>(
+    ads: &mut CallbackAds<M>,
+    delay: &mut CallbackDelay,
+    index: u8,
+    n: u32,
+) -> Result<i32, Error> {
+    let channel = channel::<M>(index)?;
+
+    Ok(ads.measure_software_offset_blocking(delay, channel, n))
+}
+
+fn calibrate_gain<M: ADSModel>(
+    ads: &mut CallbackAds<M>,
+    delay: &mut CallbackDelay,
+    index: u8,
+    expected: i32,
+    n: u32,
+) -> Result<i32, Error> {
+    let channel = channel::<M>(index)?;
+
+    ads.calibrate_system_gain_blocking(delay, channel, expected, n)
+        .ok_or(Error::InvalidCalibration)
+}
+
+/// Returns the status code for an error
+fn status(error: Error) -> i32 {
+    match error {
+        Error::InvalidChannel => ADS123X_ERR_INVALID_CHANNEL,
+        Error::WakeTimeout => ADS123X_ERR_WAKE_TIMEOUT,
+        Error::Unstable => ADS123X_ERR_UNSTABLE,
+        Error::InvalidCalibration => ADS123X_ERR_INVALID_CALIBRATION,
+        Error::Overflow => ADS123X_ERR_OVERFLOW,
+        Error::CalibrationTimeout => ADS123X_ERR_CALIBRATION_TIMEOUT,
+        _ => ADS123X_ERR_OTHER,
+    }
+}
+
+/// Writes the result of `f` through `out`, or returns the status of its error
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+unsafe fn write_result<V>(out: *mut V, f: impl FnOnce() -> Result<V, Error>) -> i32 {
+    if out.is_null() {
+        return ADS123X_ERR_INVALID_ARGUMENT;
+    }
+
+    match f() {
+        Ok(value) => {
+            *out = value;
+            ADS123X_OK
+        }
+        Err(error) => status(error),
+    }
+}
+
+/// Returns the number of bytes of storage needed for a handle
+#[no_mangle]
+pub extern "C" fn ads123x_handle_size() -> usize {
+    size_of::<AdsHandle>()
+}
+
+/// Returns the alignment needed for the storage of a handle
+#[no_mangle]
+pub extern "C" fn ads123x_handle_align() -> usize {
+    align_of::<AdsHandle>()
+}
+
+/// Initializes a handle for the given model (1232, 1234, 1130, or 1131) in
+/// `storage` and returns it, or returns null if any argument is invalid
+///
+/// The chip isn't reset, see [`ads123x_reset`].
+///
+/// # Safety
+///
+/// `storage` must be valid for writes of `len` bytes, and `callbacks` must
+/// point to a valid callback table. The handle must not be used after the
+/// storage is freed or reused.
+#[no_mangle]
+pub unsafe extern "C" fn ads123x_init(
+    storage: *mut c_void,
+    len: usize,
+    model: u32,
+    callbacks: *const AdsCallbacks,
+) -> *mut AdsHandle {
+    let handle = storage as *mut AdsHandle;
+
+    if handle.is_null()
+        || !handle.is_aligned()
+        || len < size_of::<AdsHandle>()
+        || callbacks.is_null()
+    {
+        return core::ptr::null_mut();
+    }
+
+    let callbacks = *callbacks;
+    let driver = match model {
+        #[cfg(feature = "ads1232")]
+        1232 => Driver::Ads1232(driver(callbacks)),
+        #[cfg(feature = "ads1234")]
+        1234 => Driver::Ads1234(driver(callbacks)),
+        1130 => Driver::Ads1130(driver(callbacks)),
+        1131 => Driver::Ads1131(driver(callbacks)),
+        _ => return core::ptr::null_mut(),
+    };
+
+    handle.write(AdsHandle {
+        driver,
+        delay: CallbackDelay(callbacks),
+    });
+
+    handle
+}
+
+/// Power cycles the chip, see [`ADS123X::reset_blocking`]
+///
+/// # Safety
+///
+/// `handle` must be null or have been returned by [`ads123x_init`].
+#[no_mangle]
+pub unsafe extern "C" fn ads123x_reset(handle: *mut AdsHandle) -> i32 {
+    let Some(handle) = handle.as_mut() else {
+        return ADS123X_ERR_INVALID_ARGUMENT;
+    };
+
+    with_driver!(handle, ads => ads.reset_blocking(&mut handle.delay));
+
+    ADS123X_OK
+}
+
+/// Reads the corrected value of a channel into `value`, see
+/// [`ADS123X::read_calibrated_blocking`]
+///
+/// # Safety
+///
+/// `handle` must be null or have been returned by [`ads123x_init`], and
+/// `value` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn ads123x_read(handle: *mut AdsHandle, channel: u8, value: *mut i32) -> i32 {
+    let Some(handle) = handle.as_mut() else {
+        return ADS123X_ERR_INVALID_ARGUMENT;
+    };
+
+    with_driver!(handle, ads => write_result(value, || read(ads, &mut handle.delay, channel)))
+}
+
+/// Averages `n` reads of a channel and stores the result as its software
+/// offset (which is also written to `offset`), see
+/// [`ADS123X::measure_software_offset_blocking`]
+///
+/// # Safety
+///
+/// `handle` must be null or have been returned by [`ads123x_init`], and
+/// `offset` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn ads123x_tare(
+    handle: *mut AdsHandle,
+    channel: u8,
+    n: u32,
+    offset: *mut i32,
+) -> i32 {
+    let Some(handle) = handle.as_mut() else {
+        return ADS123X_ERR_INVALID_ARGUMENT;
+    };
+
+    with_driver!(handle, ads => write_result(offset, || tare(ads, &mut handle.delay, channel, n)))
+}
+
+/// Runs the chip's internal offset calibration, see
+/// [`ADS123X::calibrate_offset_blocking`]
+///
+/// # Safety
+///
+/// `handle` must be null or have been returned by [`ads123x_init`].
+#[no_mangle]
+pub unsafe extern "C" fn ads123x_calibrate_offset(handle: *mut AdsHandle) -> i32 {
+    let Some(handle) = handle.as_mut() else {
+        return ADS123X_ERR_INVALID_ARGUMENT;
+    };
+
+    match with_driver!(handle, ads => ads.calibrate_offset_blocking(&mut handle.delay)) {
+        Ok(_) => ADS123X_OK,
+        Err(error) => status(error),
+    }
+}
+
+/// Averages `n` reads of a channel while a known input is applied and stores
+/// the system gain that maps it to `expected` counts (which is also written to
+/// `gain`), see [`ADS123X::calibrate_system_gain_blocking`]
+///
+/// # Safety
+///
+/// `handle` must be null or have been returned by [`ads123x_init`], and `gain`
+/// must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn ads123x_calibrate_gain(
+    handle: *mut AdsHandle,
+    channel: u8,
+    expected: i32,
+    n: u32,
+    gain: *mut i32,
+) -> i32 {
+    let Some(handle) = handle.as_mut() else {
+        return ADS123X_ERR_INVALID_ARGUMENT;
+    };
+
+    with_driver!(handle, ads => write_result(gain, || {
+        calibrate_gain(ads, &mut handle.delay, channel, expected, n)
+    }))
+}
+
+/// Puts the chip into standby, see [`ADS123X::enter_standby_blocking`]
+///
+/// # Safety
+///
+/// `handle` must be null or have been returned by [`ads123x_init`].
+#[no_mangle]
+pub unsafe extern "C" fn ads123x_standby(handle: *mut AdsHandle) -> i32 {
+    let Some(handle) = handle.as_mut() else {
+        return ADS123X_ERR_INVALID_ARGUMENT;
+    };
+
+    with_driver!(handle, ads => ads.enter_standby_blocking(&mut handle.delay));
+
+    ADS123X_OK
+}
+
+/// Wakes the chip from standby, see [`ADS123X::wake_blocking`]
+///
+/// # Safety
+///
+/// `handle` must be null or have been returned by [`ads123x_init`].
+#[no_mangle]
+pub unsafe extern "C" fn ads123x_wake(handle: *mut AdsHandle) -> i32 {
+    let Some(handle) = handle.as_mut() else {
+        return ADS123X_ERR_INVALID_ARGUMENT;
+    };
+
+    match with_driver!(handle, ads => ads.wake_blocking(&mut handle.delay)) {
+        Ok(()) => ADS123X_OK,
+        Err(error) => status(error),
+    }
+}
@@ -0,0 +1,153 @@
+//! Cutoff control for filling and dosing
+//!
+//! When material is dosed onto a scale, some of it is still in flight between
+//! the feeder and the container when the feed is cut off, and the final
+//! weight overshoots the point at which the cutoff was signaled. A
+//! [`FillController`] estimates the feed rate from consecutive weights, and
+//! signals the cutoff as soon as the current weight plus the material expected
+//! to arrive during the configured fall time plus a pre-act amount reaches the
+//! target.
+//!
+//! The pre-act amount corrects for whatever the rate estimate doesn't capture
+//! (e.g. the feeder running down after being switched off), and is learned
+//! from the overshoot of each completed cycle passed to
+//! [`FillController::finish`].
+
+use crate::units::Weight;
+
+/// Signals when to cut off the feed so that a fill ends at its target weight
+#[derive(Debug, Clone)]
+pub struct FillController {
+    target: Weight,
+    preact: Weight,
+    fall_time_us: u32,
+    learning_percent: u32,
+    rate_mg_per_s: Option<i64>,
+    last: Option<(Weight, u64)>,
+    cut_off: bool,
+}
+
+impl FillController {
+    /// Creates a controller for the given target weight, with no pre-act
+    /// amount or fall time, which learns half of each cycle's overshoot
+    pub const fn new(target: Weight) -> Self {
+        Self {
+            target,
+            preact: Weight::ZERO,
+            fall_time_us: 0,
+            learning_percent: 50,
+            rate_mg_per_s: None,
+            last: None,
+            cut_off: false,
+        }
+    }
+
+    /// Sets the initial pre-act amount, e.g. one learned in a previous session
+    pub const fn with_preact(mut self, preact: Weight) -> Self {
+        self.preact = preact;
+        self
+    }
+
+    /// Sets the time material takes to travel from the feeder to the
+    /// container, over which the material in flight is predicted from the feed
+    /// rate
+    pub const fn with_fall_time_us(mut self, fall_time_us: u32) -> Self {
+        self.fall_time_us = fall_time_us;
+        self
+    }
+
+    /// Sets the time material takes to travel from the feeder to the
+    /// container
+    #[cfg(feature = "fugit")]
+    pub const fn with_fall_time(self, fall_time: fugit::MicrosDurationU32) -> Self {
+        self.with_fall_time_us(fall_time.ticks())
+    }
+
+    /// Sets the percentage of each cycle's overshoot that is added to the
+    /// pre-act amount (at most 100), or 0 to keep it fixed
+    pub const fn with_learning_percent(mut self, learning_percent: u32) -> Self {
+        self.learning_percent = if learning_percent > 100 {
+            100
+        } else {
+            learning_percent
+        };
+        self
+    }
+
+    pub fn target(&self) -> Weight {
+        self.target
+    }
+
+    pub fn set_target(&mut self, target: Weight) {
+        self.target = target;
+    }
+
+    /// Returns the current pre-act amount, e.g. to persist it
+    pub fn preact(&self) -> Weight {
+        self.preact
+    }
+
+    /// Returns the estimated feed rate in milligrams per second, or `None`
+    /// until two weights have been added in the current cycle
+    pub fn rate_mg_per_s(&self) -> Option<i64> {
+        self.rate_mg_per_s
+    }
+
+    /// Prepares for a new cycle, forgetting the rate estimate of the previous
+    /// one
+    pub fn start(&mut self) {
+        self.rate_mg_per_s = None;
+        self.last = None;
+        self.cut_off = false;
+    }
+
+    /// Returns the weight a fill is expected to end at if the feed were cut off
+    /// at the given current weight
+    pub fn predicted_final(&self, weight: Weight) -> Weight {
+        let in_flight =
+            self.rate_mg_per_s.unwrap_or(0) as i128 * self.fall_time_us as i128 / 1_000_000;
+
+        weight + Weight::from_milligrams(in_flight as i64) + self.preact
+    }
+
+    /// Adds a weight measured at the given time in microseconds (e.g. from
+    /// [`crate::Clock::now_us`]) and returns whether the feed should be cut off
+    ///
+    /// Once the cutoff has been signaled this keeps returning `true` until the
+    /// next call to [`Self::start`].
+    pub fn push(&mut self, weight: Weight, timestamp_us: u64) -> bool {
+        if let Some((last_weight, last_us)) = self.last {
+            let elapsed_us = timestamp_us.saturating_sub(last_us);
+
+            if elapsed_us > 0 {
+                let delta = (weight - last_weight).milligrams() as i128;
+                let rate = (delta * 1_000_000 / elapsed_us as i128) as i64;
+
+                // Smooth the rate over roughly the last four weights, as the
+                // difference of two noisy readings is noisier still
+                self.rate_mg_per_s = Some(match self.rate_mg_per_s {
+                    Some(smoothed) => smoothed + (rate - smoothed) / 4,
+                    None => rate,
+                });
+            }
+        }
+
+        self.last = Some((weight, timestamp_us));
+
+        if !self.cut_off && self.predicted_final(weight) >= self.target {
+            self.cut_off = true;
+        }
+
+        self.cut_off
+    }
+
+    /// Completes a cycle with the weight the fill settled at, learns from its
+    /// overshoot, and returns the overshoot (negative if the fill fell short)
+    pub fn finish(&mut self, settled: Weight) -> Weight {
+        let overshoot = settled - self.target;
+
+        self.preact += overshoot * self.learning_percent as i64 / 100;
+
+        overshoot
+    }
+}
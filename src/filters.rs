@@ -0,0 +1,266 @@
+//! Digital filters for post-processing the stream of conversion results
+
+/// Hampel (median absolute deviation) outlier rejection filter over a trailing
+/// window of `N` samples
+///
+/// Each new sample is compared against the median of the window. If it
+/// deviates from the median by more than `k` scaled median absolute deviations
+/// it is treated as an outlier (e.g. a knock on a vibrating scale) and replaced
+/// by the median, otherwise it is passed through unchanged. The MAD is scaled
+/// by 1.4826 so that `k` is in units of standard deviations for normally
+/// distributed noise, making 3 the usual choice.
+///
+/// Unlike averaging, a single large spike has no effect on the output at all,
+/// while genuine step changes pass through once they make up more than half of
+/// the window.
+#[derive(Debug, Clone)]
+pub struct Hampel<const N: usize> {
+    window: [i32; N],
+    len: usize,
+    next: usize,
+    k: u32,
+}
+
+impl<const N: usize> Hampel<N> {
+    pub const fn new(k: u32) -> Self {
+        Self {
+            window: [0; N],
+            len: 0,
+            next: 0,
+            k,
+        }
+    }
+
+    /// Adds a sample to the window and returns either the sample itself or, if
+    /// it is an outlier, the median of the window
+    ///
+    /// Until the window has filled up the statistics are computed over the
+    /// samples received so far.
+    pub fn filter(&mut self, sample: i32) -> i32 {
+        if N == 0 {
+            return sample;
+        }
+
+        self.window[self.next] = sample;
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+
+        let mut scratch = [0i32; N];
+        let scratch = &mut scratch[..self.len];
+
+        scratch.copy_from_slice(&self.window[..self.len]);
+        let median = median_of(scratch);
+
+        for value in scratch.iter_mut() {
+            *value = (*value as i64 - median as i64)
+                .unsigned_abs()
+                .min(i32::MAX as u64) as i32;
+        }
+        let mad = median_of(scratch) as i64;
+
+        // 1.4826 * k * MAD, kept in integer arithmetic
+        let threshold = mad * self.k as i64 * 14_826 / 10_000;
+
+        if (sample as i64 - median as i64).abs() > threshold {
+            median
+        } else {
+            sample
+        }
+    }
+
+    /// Forgets all previously seen samples
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.next = 0;
+    }
+}
+
+/// Sorts the values in place and returns their median (the mean of the two
+/// middle values when there is an even number of them)
+fn median_of(values: &mut [i32]) -> i32 {
+    values.sort_unstable();
+
+    let mid = values.len() / 2;
+
+    if values.len() % 2 == 1 {
+        values[mid]
+    } else {
+        ((values[mid - 1] as i64 + values[mid] as i64) / 2) as i32
+    }
+}
+
+/// Largest `ratio^STAGES` for which the integrators of a [`Cic`] can't
+/// overflow an i64
+const CIC_MAX_GAIN: i64 = 1 << 32;
+
+/// Cascaded integrator-comb decimation filter with `STAGES` stages
+///
+/// This produces one output for every `ratio` input samples using only
+/// additions and a single division per output, which makes it far cheaper
+/// than a long FIR filter on Cortex-M0 class parts (e.g. for reducing 80 SPS
+/// conversions to ~1 Hz display values). Outputs are normalized so that a
+/// constant input produces the same constant output. The first `STAGES`
+/// outputs after creation or a reset are transients and should be discarded.
+///
+/// The integrators rely on two's complement wrap-around, which is harmless as
+/// long as the largest output, `2^31 * ratio^STAGES` for i32 samples, fits in
+/// an i64. [`Self::new`] therefore rejects parameters where `ratio^STAGES`
+/// exceeds `2^32`.
+#[derive(Debug, Clone)]
+pub struct Cic<const STAGES: usize> {
+    ratio: u32,
+    gain: i64,
+    integrators: [i64; STAGES],
+    combs: [i64; STAGES],
+    phase: u32,
+    compensation: Option<[i64; 2]>,
+    compensate: bool,
+}
+
+impl<const STAGES: usize> Cic<STAGES> {
+    /// Creates a filter that decimates by `ratio` (at least 1), or returns
+    /// `None` if `ratio^STAGES` exceeds `2^32` so that the output would
+    /// overflow
+    pub fn new(ratio: u32) -> Option<Self> {
+        let ratio = ratio.max(1);
+        let gain = (ratio as i64)
+            .checked_pow(STAGES as u32)
+            .filter(|&gain| gain <= CIC_MAX_GAIN)?;
+
+        Some(Self {
+            ratio,
+            gain,
+            integrators: [0; STAGES],
+            combs: [0; STAGES],
+            phase: 0,
+            compensation: None,
+            compensate: false,
+        })
+    }
+
+    /// Enables a 3-tap FIR compensator on the decimated output, which flattens
+    /// the CIC's passband droop from DC up to around a quarter of the output
+    /// rate at the cost of delaying the output by one more sample
+    pub fn with_compensation(mut self) -> Self {
+        self.compensate = true;
+        self
+    }
+
+    /// Adds a sample and returns a decimated output once every `ratio` samples
+    pub fn filter(&mut self, sample: i32) -> Option<i32> {
+        let mut value = sample as i64;
+
+        for integrator in self.integrators.iter_mut() {
+            *integrator = integrator.wrapping_add(value);
+            value = *integrator;
+        }
+
+        self.phase += 1;
+
+        if self.phase < self.ratio {
+            return None;
+        }
+
+        self.phase = 0;
+
+        for comb in self.combs.iter_mut() {
+            let delayed = *comb;
+            *comb = value;
+            value = value.wrapping_sub(delayed);
+        }
+
+        let output = value / self.gain;
+
+        if !self.compensate {
+            return Some(output as i32);
+        }
+
+        // Coefficients of [-c, 1 + 2c, -c] with c ≈ STAGES / 16, which matches
+        // the droop at a quarter of the output rate to within a few percent for
+        // up to 4 stages
+        let [older, old] = self.compensation.unwrap_or([output, output]);
+        self.compensation = Some([old, output]);
+
+        let c = STAGES as i64;
+        let compensated = ((16 + 2 * c) * old - c * (older + output)) / 16;
+
+        Some(compensated as i32)
+    }
+
+    /// Clears the filter state
+    pub fn reset(&mut self) {
+        self.integrators = [0; STAGES];
+        self.combs = [0; STAGES];
+        self.phase = 0;
+        self.compensation = None;
+    }
+}
+
+/// Finite impulse response filter with `TAPS` user-supplied fixed-point
+/// coefficients
+///
+/// Each output is `sum(coefficients[k] * x[n - k]) >> fractional_bits`, where
+/// `coefficients[0]` applies to the newest sample, accumulated in an i64. This
+/// makes it possible to apply custom low-pass or notch responses (e.g. to
+/// reject 50/60 Hz mains pickup) designed with an external tool. The history
+/// is seeded with the first sample, so a filter with unity DC gain starts at
+/// the input value instead of ramping up from zero.
+#[derive(Debug, Clone)]
+pub struct Fir<const TAPS: usize> {
+    coefficients: [i32; TAPS],
+    fractional_bits: u32,
+    history: [i32; TAPS],
+    next: usize,
+    primed: bool,
+}
+
+impl<const TAPS: usize> Fir<TAPS> {
+    /// Creates a filter from coefficients with `fractional_bits` fractional
+    /// bits, so that `1 << fractional_bits` represents a coefficient of 1.0
+    pub const fn new(coefficients: [i32; TAPS], fractional_bits: u32) -> Self {
+        Self {
+            coefficients,
+            fractional_bits,
+            history: [0; TAPS],
+            next: 0,
+            primed: false,
+        }
+    }
+
+    /// Returns the filter coefficients
+    pub fn coefficients(&self) -> &[i32; TAPS] {
+        &self.coefficients
+    }
+
+    /// Adds a sample and returns the filtered output
+    pub fn filter(&mut self, sample: i32) -> i32 {
+        if TAPS == 0 {
+            return sample;
+        }
+
+        if !self.primed {
+            self.history = [sample; TAPS];
+            self.primed = true;
+        }
+
+        self.history[self.next] = sample;
+
+        let mut acc = 0i64;
+        let mut index = self.next;
+
+        for &coefficient in self.coefficients.iter() {
+            acc = acc.saturating_add(coefficient as i64 * self.history[index] as i64);
+            index = if index == 0 { TAPS - 1 } else { index - 1 };
+        }
+
+        self.next = (self.next + 1) % TAPS;
+
+        (acc >> self.fractional_bits.min(63)).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+
+    /// Forgets all previously seen samples
+    pub fn reset(&mut self) {
+        self.next = 0;
+        self.primed = false;
+    }
+}
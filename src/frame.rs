@@ -0,0 +1,289 @@
+//! Compact binary encoding of samples for streaming to a host
+//!
+//! Every sample is packed into a fixed [`FRAME_LEN`] byte frame, with all
+//! multi-byte fields in little endian order:
+//!
+//! | Offset | Size | Field                                              |
+//! |--------|------|----------------------------------------------------|
+//! | 0      | 1    | Channel index                                      |
+//! | 1      | 2    | Sequence number (wraps around)                     |
+//! | 3      | 4    | Value                                              |
+//! | 7      | 4    | Microseconds since the previous sample (saturates) |
+//!
+//! The firmware side uses an [`Encoder`] to number and timestamp the samples,
+//! while the host side (with the `std` feature) reads them back with a
+//! [`Decoder`], which also detects frames that were lost along the way.
+//!
+//! Over lossy serial links the frames can instead be sent with
+//! [`Encoder::encode_cobs`], which appends a CRC-16/CCITT-FALSE of the frame
+//! (little endian), applies consistent overhead byte stuffing (COBS), and
+//! terminates the result with a zero byte. The receiver can then resynchronize
+//! at the next zero byte after losing or corrupting any part of the stream, and
+//! [`Decoder::new_cobs`] skips (and counts) frames that fail the CRC check.
+
+#[cfg(feature = "std")]
+use crate::crc::cobs_decode;
+use crate::crc::{cobs_encode, crc16};
+
+/// Length of an encoded frame in bytes
+pub const FRAME_LEN: usize = 11;
+
+/// Length of a COBS encoded frame in bytes, including its CRC and zero
+/// delimiter
+pub const COBS_FRAME_LEN: usize = FRAME_LEN + 2 + 2;
+
+/// A sample as carried by a frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct Sample {
+    /// Index of the channel the sample was read from (0 for AIN1, 1 for AIN2,
+    /// and so on)
+    pub channel: u8,
+    pub sequence: u16,
+    pub value: i32,
+    /// Microseconds since the previous sample, or 0 for the first one
+    pub timestamp_delta_us: u32,
+}
+
+impl Sample {
+    pub fn to_bytes(&self) -> [u8; FRAME_LEN] {
+        let mut frame = [0; FRAME_LEN];
+
+        frame[0] = self.channel;
+        frame[1..3].copy_from_slice(&self.sequence.to_le_bytes());
+        frame[3..7].copy_from_slice(&self.value.to_le_bytes());
+        frame[7..11].copy_from_slice(&self.timestamp_delta_us.to_le_bytes());
+
+        frame
+    }
+
+    pub fn from_bytes(frame: &[u8; FRAME_LEN]) -> Self {
+        Self {
+            channel: frame[0],
+            sequence: u16::from_le_bytes([frame[1], frame[2]]),
+            value: i32::from_le_bytes([frame[3], frame[4], frame[5], frame[6]]),
+            timestamp_delta_us: u32::from_le_bytes([frame[7], frame[8], frame[9], frame[10]]),
+        }
+    }
+}
+
+/// Numbers and timestamps consecutive samples and encodes them into frames
+#[derive(Debug, Clone, Default)]
+pub struct Encoder {
+    sequence: u16,
+    last_timestamp_us: Option<u64>,
+}
+
+impl Encoder {
+    pub const fn new() -> Self {
+        Self {
+            sequence: 0,
+            last_timestamp_us: None,
+        }
+    }
+
+    /// Builds the next sample from a value read from the given channel index
+    /// at the given time in microseconds (e.g. from [`crate::Clock::now_us`])
+    pub fn next_sample(&mut self, channel: u8, value: i32, timestamp_us: u64) -> Sample {
+        let delta = match self.last_timestamp_us {
+            Some(last) => timestamp_us.saturating_sub(last).min(u32::MAX as u64) as u32,
+            None => 0,
+        };
+
+        let sample = Sample {
+            channel,
+            sequence: self.sequence,
+            value,
+            timestamp_delta_us: delta,
+        };
+
+        self.sequence = self.sequence.wrapping_add(1);
+        self.last_timestamp_us = Some(timestamp_us);
+
+        sample
+    }
+
+    /// Builds the next sample and returns it encoded as a frame
+    pub fn encode(&mut self, channel: u8, value: i32, timestamp_us: u64) -> [u8; FRAME_LEN] {
+        self.next_sample(channel, value, timestamp_us).to_bytes()
+    }
+
+    /// Builds the next sample and returns it encoded as a frame with a CRC,
+    /// COBS encoded, and terminated by a zero byte
+    pub fn encode_cobs(
+        &mut self,
+        channel: u8,
+        value: i32,
+        timestamp_us: u64,
+    ) -> [u8; COBS_FRAME_LEN] {
+        let mut payload = [0; FRAME_LEN + 2];
+
+        payload[..FRAME_LEN].copy_from_slice(&self.encode(channel, value, timestamp_us));
+        let crc = crc16(&payload[..FRAME_LEN]);
+        payload[FRAME_LEN..].copy_from_slice(&crc.to_le_bytes());
+
+        let mut output = [0; COBS_FRAME_LEN];
+        cobs_encode(&payload, &mut output);
+
+        output
+    }
+}
+
+/// Reads frames from a byte stream on the host
+#[cfg(feature = "std")]
+pub struct Decoder<R> {
+    reader: R,
+    cobs: bool,
+    next_sequence: Option<u16>,
+    timestamp_us: u64,
+    dropped: u64,
+    corrupted: u64,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> Decoder<R> {
+    /// Creates a decoder for a stream of plain frames
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            cobs: false,
+            next_sequence: None,
+            timestamp_us: 0,
+            dropped: 0,
+            corrupted: 0,
+        }
+    }
+
+    /// Creates a decoder for a stream of frames produced by
+    /// [`Encoder::encode_cobs`]
+    pub fn new_cobs(reader: R) -> Self {
+        Self {
+            cobs: true,
+            ..Self::new(reader)
+        }
+    }
+
+    /// Reads the next sample, returning `None` at the end of the stream
+    ///
+    /// A stream of plain frames that ends part way through a frame is reported
+    /// as an [`std::io::ErrorKind::UnexpectedEof`] error, while a partial COBS
+    /// frame is counted as corrupted.
+    pub fn read_sample(&mut self) -> std::io::Result<Option<Sample>> {
+        let frame = if self.cobs {
+            match self.read_cobs_frame()? {
+                Some(frame) => frame,
+                None => return Ok(None),
+            }
+        } else {
+            let mut frame = [0; FRAME_LEN];
+
+            match self.reader.read(&mut frame[..1])? {
+                0 => return Ok(None),
+                _ => self.reader.read_exact(&mut frame[1..])?,
+            }
+
+            frame
+        };
+
+        let sample = Sample::from_bytes(&frame);
+
+        if let Some(expected) = self.next_sequence {
+            self.dropped += sample.sequence.wrapping_sub(expected) as u64;
+        }
+
+        self.next_sequence = Some(sample.sequence.wrapping_add(1));
+        self.timestamp_us += sample.timestamp_delta_us as u64;
+
+        Ok(Some(sample))
+    }
+
+    /// Reads zero delimited blocks until one decodes to a frame with a valid
+    /// CRC
+    fn read_cobs_frame(&mut self) -> std::io::Result<Option<[u8; FRAME_LEN]>> {
+        let mut block = [0; COBS_FRAME_LEN];
+
+        loop {
+            let mut len = 0;
+            let mut overflowed = false;
+
+            loop {
+                let mut byte = [0];
+
+                if self.reader.read(&mut byte)? == 0 {
+                    if len > 0 || overflowed {
+                        self.corrupted += 1;
+                    }
+
+                    return Ok(None);
+                }
+
+                if byte[0] == 0 {
+                    break;
+                }
+
+                match block.get_mut(len) {
+                    Some(slot) => *slot = byte[0],
+                    None => overflowed = true,
+                }
+
+                len += 1;
+            }
+
+            // Consecutive delimiters don't carry a frame
+            if len == 0 {
+                continue;
+            }
+
+            let mut payload = [0; FRAME_LEN + 2];
+            let valid = !overflowed
+                && cobs_decode(&block[..len], &mut payload) == Some(payload.len())
+                && crc16(&payload[..FRAME_LEN]).to_le_bytes() == payload[FRAME_LEN..];
+
+            if valid {
+                let mut frame = [0; FRAME_LEN];
+                frame.copy_from_slice(&payload[..FRAME_LEN]);
+
+                return Ok(Some(frame));
+            }
+
+            self.corrupted += 1;
+        }
+    }
+
+    /// Time of the most recently read sample in microseconds, relative to the
+    /// first sample read
+    ///
+    /// Samples that were dropped in transit also drop their time delta, so
+    /// this falls behind the firmware's clock whenever [`Self::dropped`]
+    /// increases.
+    pub fn timestamp_us(&self) -> u64 {
+        self.timestamp_us
+    }
+
+    /// Number of frames missing from the stream so far, as detected from gaps
+    /// in the sequence numbers
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Number of COBS frames that were skipped because they were truncated or
+    /// failed the CRC check
+    pub fn corrupted(&self) -> u64 {
+        self.corrupted
+    }
+
+    /// Releases the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> Iterator for Decoder<R> {
+    type Item = std::io::Result<Sample>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_sample().transpose()
+    }
+}
@@ -0,0 +1,145 @@
+//! Interleaving of two converters sampling the same signal
+//!
+//! Both chips are powered up half a conversion period apart so that their
+//! conversions complete in alternating order, and reading from the pair
+//! alternates between them to deliver roughly twice the data rate of a single
+//! chip.
+//!
+//! Each chip runs from its own oscillator unless they share an external clock
+//! on CLKIN, so their phases will slowly drift relative to each other. Call
+//! [`Interleaved::start_blocking`] (or [`Interleaved::start`]) again
+//! periodically to realign them if this matters for the application.
+
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{InputPin, OutputPin, StatefulOutputPin},
+};
+
+use crate::{sign_extend, ADSModel, AdsHooks, Clock, TimingProfile, ADS123X};
+
+/// A pair of identically wired converters whose conversions are staggered by
+/// half a conversion period
+///
+/// Each chip is read from whichever channel it currently has selected, so
+/// models with a mux should be switched to the desired input (e.g. with a
+/// regular read) before being interleaved.
+pub struct Interleaved<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    ads: [ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>; 2],
+    next: usize,
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> Interleaved<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    pub fn new(
+        first: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>,
+        second: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>,
+    ) -> Self {
+        Self {
+            ads: [first, second],
+            next: 0,
+        }
+    }
+
+    /// Releases the two drivers in the order they were given to
+    /// [`Self::new`]
+    pub fn free(self) -> [ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>; 2] {
+        self.ads
+    }
+
+    /// Resets the first chip, waits half a conversion period at the SPEED in
+    /// its [`crate::Config`], and then resets the second chip so that their
+    /// conversions are staggered
+    pub fn start_blocking(&mut self, delay: &mut impl DelayNs) {
+        let period_us = self.ads[0].config().speed.conversion_period_us();
+
+        self.ads[0].reset_blocking(delay);
+        delay.delay_us(period_us / 2);
+        self.ads[1].reset_blocking(delay);
+
+        self.next = 0;
+    }
+
+    /// Reads the next conversion from whichever chip is due and returns it
+    /// with that chip's software offset, system gain, and linearity
+    /// corrections for the selected channel applied
+    ///
+    /// Consecutive calls alternate between the two chips, so calling this in
+    /// a loop yields a merged stream at twice the data rate of a single chip.
+    /// Correcting each sample with its own chip's calibration keeps the
+    /// difference between the two offsets from showing up as a sawtooth.
+    pub fn read_blocking(&mut self, delay: &mut impl DelayNs) -> i32 {
+        let ads = &mut self.ads[self.next];
+        let raw = sign_extend(ads.read_internal_blocking(delay, None), M::BITS);
+        self.next ^= 1;
+
+        Self::correct(ads, raw)
+    }
+
+    /// Applies the corrections of the chip a sample was read from, using its
+    /// default channel if the mux pins don't select a valid one
+    fn correct(ads: &mut ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>, raw: i32) -> i32 {
+        let channel = ads.selected_channel().unwrap_or(ads.default_channel());
+
+        ads.correct(channel, raw)
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> Interleaved<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin + embedded_hal_async::digital::Wait,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Resets the first chip, waits half a conversion period at the SPEED in
+    /// its [`crate::Config`], and then resets the second chip asynchronously
+    /// so that their conversions are staggered
+    pub async fn start(&mut self, delay: &mut impl embedded_hal_async::delay::DelayNs) {
+        let period_us = self.ads[0].config().speed.conversion_period_us();
+
+        self.ads[0].reset(delay).await;
+        delay.delay_us(period_us / 2).await;
+        self.ads[1].reset(delay).await;
+
+        self.next = 0;
+    }
+
+    /// Reads the next conversion from whichever chip is due asynchronously and
+    /// returns it with that chip's corrections applied
+    ///
+    /// See [`Self::read_blocking`] for details.
+    pub async fn read(&mut self, delay: &mut impl embedded_hal_async::delay::DelayNs) -> i32 {
+        let ads = &mut self.ads[self.next];
+        let raw = sign_extend(ads.read_internal(delay, None).await, M::BITS);
+        self.next ^= 1;
+
+        Self::correct(ads, raw)
+    }
+}
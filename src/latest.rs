@@ -0,0 +1,207 @@
+//! Lock-free sharing of the most recent reading
+//!
+//! A [`LatestSample`] is typically placed in a `static`, updated by whichever
+//! context performs the conversions, and read by any other context (e.g. a
+//! display refresh or an interrupt handler) without taking a lock. Each
+//! reading carries the time it was published so readers can tell when the
+//! value has gone stale, e.g. because the sampling task has stalled.
+//!
+//! Values that don't fit into 64 bits, such as a whole
+//! [`crate::frame::Sample`], can be handed over through a [`TripleBuffer`]
+//! instead.
+
+use core::cell::UnsafeCell;
+
+use portable_atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
+
+/// A reading published to a [`LatestSample`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct Sample {
+    pub value: i32,
+    /// Time the sample was published in milliseconds, which wraps around
+    /// after roughly 49 days
+    pub timestamp_ms: u32,
+}
+
+impl Sample {
+    /// Returns how long ago the sample was published, given the current time
+    /// in microseconds (as returned by [`crate::Clock::now_us`])
+    pub fn age_ms(&self, now_us: u64) -> u32 {
+        ((now_us / 1_000) as u32).wrapping_sub(self.timestamp_ms)
+    }
+
+    /// Returns true if the sample was published more than `max_age_ms` ago
+    pub fn is_stale(&self, now_us: u64, max_age_ms: u32) -> bool {
+        self.age_ms(now_us) > max_age_ms
+    }
+}
+
+/// A cell holding the most recently published [`Sample`]
+///
+/// The value and its timestamp are packed into a single atomic so readers
+/// never observe a value paired with the wrong timestamp.
+#[derive(Debug, Default)]
+pub struct LatestSample {
+    packed: AtomicU64,
+    valid: AtomicBool,
+}
+
+impl LatestSample {
+    pub const fn new() -> Self {
+        Self {
+            packed: AtomicU64::new(0),
+            valid: AtomicBool::new(false),
+        }
+    }
+
+    /// Replaces the stored sample with `value`, timestamped with the current
+    /// time in microseconds (as returned by [`crate::Clock::now_us`])
+    pub fn publish(&self, value: i32, now_us: u64) {
+        let timestamp_ms = (now_us / 1_000) as u32;
+        let packed = ((value as u32 as u64) << 32) | timestamp_ms as u64;
+
+        self.packed.store(packed, Ordering::Release);
+        self.valid.store(true, Ordering::Release);
+    }
+
+    /// Returns the most recently published sample, or `None` if nothing has
+    /// been published since the cell was created or cleared
+    pub fn latest(&self) -> Option<Sample> {
+        if !self.valid.load(Ordering::Acquire) {
+            return None;
+        }
+
+        let packed = self.packed.load(Ordering::Acquire);
+
+        Some(Sample {
+            value: (packed >> 32) as u32 as i32,
+            timestamp_ms: packed as u32,
+        })
+    }
+
+    /// Returns the most recently published value if it is no older than
+    /// `max_age_ms`
+    pub fn fresh(&self, now_us: u64, max_age_ms: u32) -> Option<i32> {
+        self.latest()
+            .filter(|sample| !sample.is_stale(now_us, max_age_ms))
+            .map(|sample| sample.value)
+    }
+
+    /// Forgets the stored sample, e.g. while the converter is in standby
+    pub fn clear(&self) {
+        self.valid.store(false, Ordering::Release);
+    }
+}
+
+/// Flag set in [`TripleBuffer::middle`] while the middle slot holds a value
+/// that the consumer hasn't picked up yet
+const FRESH: u8 = 0b100;
+
+/// Mask of the slot index in [`TripleBuffer::middle`]
+const INDEX: u8 = 0b011;
+
+/// A wait-free cell for handing the newest value of a type that is too large
+/// for a single atomic (such as a [`crate::frame::Sample`]) from one producer
+/// to one consumer
+///
+/// The producer always writes into a slot of its own and then swaps it with
+/// the middle slot, while the consumer swaps the middle slot with its own
+/// slot whenever it holds a newer value. Neither side ever waits for the
+/// other, so the producer can publish from an interrupt handler while the
+/// main loop is in the middle of reading, and the consumer always gets the
+/// most recently completed value. Both sides are obtained from
+/// [`Self::split`].
+pub struct TripleBuffer<T> {
+    slots: [UnsafeCell<T>; 3],
+    middle: AtomicU8,
+}
+
+// The producer and consumer each only access their own slot, plus the middle
+// slot after exchanging it through the atomic
+unsafe impl<T: Send> Sync for TripleBuffer<T> {}
+
+impl<T: Copy> TripleBuffer<T> {
+    /// Creates a buffer that reads as `initial` until the first value is
+    /// published
+    pub const fn new(initial: T) -> Self {
+        Self {
+            slots: [
+                UnsafeCell::new(initial),
+                UnsafeCell::new(initial),
+                UnsafeCell::new(initial),
+            ],
+            middle: AtomicU8::new(1),
+        }
+    }
+
+    /// Returns the producing and consuming ends of the buffer
+    pub fn split(&mut self) -> (Producer<'_, T>, Consumer<'_, T>) {
+        let buffer = &*self;
+
+        (Producer { buffer, write: 0 }, Consumer { buffer, read: 2 })
+    }
+}
+
+/// A [`TripleBuffer`] for handing complete samples from the context that reads
+/// the converter to the one that processes them
+pub type SampleBuffer = TripleBuffer<crate::frame::Sample>;
+
+/// The end of a [`TripleBuffer`] that publishes values
+pub struct Producer<'a, T> {
+    buffer: &'a TripleBuffer<T>,
+    write: usize,
+}
+
+impl<T: Copy> Producer<'_, T> {
+    /// Publishes a value, replacing any value the consumer hasn't picked up
+    pub fn publish(&mut self, value: T) {
+        // Safety: the write slot isn't reachable by the consumer until it has
+        // been swapped into the middle below
+        unsafe { *self.buffer.slots[self.write].get() = value };
+
+        let old = self
+            .buffer
+            .middle
+            .swap(self.write as u8 | FRESH, Ordering::AcqRel);
+        self.write = (old & INDEX) as usize;
+    }
+}
+
+/// The end of a [`TripleBuffer`] that reads values
+pub struct Consumer<'a, T> {
+    buffer: &'a TripleBuffer<T>,
+    read: usize,
+}
+
+impl<T: Copy> Consumer<'_, T> {
+    /// Returns the most recently published value (or the initial value if
+    /// nothing has been published yet)
+    pub fn latest(&mut self) -> T {
+        self.update();
+
+        // Safety: the read slot isn't reachable by the producer until it is
+        // swapped back into the middle
+        unsafe { *self.buffer.slots[self.read].get() }
+    }
+
+    /// Returns the most recently published value if it hasn't been returned
+    /// before
+    pub fn take(&mut self) -> Option<T> {
+        self.update().then(|| self.latest())
+    }
+
+    /// Picks up the middle slot if it holds a new value, returning whether it
+    /// did
+    fn update(&mut self) -> bool {
+        if self.buffer.middle.load(Ordering::Relaxed) & FRESH == 0 {
+            return false;
+        }
+
+        let old = self.buffer.middle.swap(self.read as u8, Ordering::AcqRel);
+        self.read = (old & INDEX) as usize;
+
+        true
+    }
+}
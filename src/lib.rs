@@ -1,23 +1,1023 @@
 //! This driver is based on the datasheet which can be found here:
 //! https://www.ti.com/lit/ds/symlink/ads1234.pdf?ts=1735781638226
+//!
+//! Errors reported by the pins are not propagated, and panic instead. Most
+//! HALs use [`core::convert::Infallible`] as the error type of their GPIOs, in
+//! which case this can never happen and the API returns plain values.
 
-#![no_std]
+#![cfg_attr(not(feature = "std"), no_std)]
+// The driver is generic over every pin, so wrappers around it inevitably name
+// long types
+#![allow(clippy::type_complexity)]
 
-use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::{marker::PhantomData, num::NonZeroU64};
 
 use embedded_hal::{
     delay::DelayNs,
     digital::{InputPin, OutputPin, PinState, StatefulOutputPin},
 };
 
+#[cfg(feature = "embedded-hal-async")]
+pub mod actor;
+pub mod adaptive;
+pub mod bridge;
+pub mod calibration;
+pub mod checkweigher;
+#[cfg(feature = "conformance")]
+pub mod conformance;
+pub mod counting;
+mod crc;
+pub mod delay;
+pub mod division;
+pub mod drift;
+#[cfg(feature = "embassy")]
+pub mod embassy;
+pub mod erased;
+pub mod events;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fill;
+pub mod filters;
+pub mod frame;
+pub mod interleave;
+#[cfg(feature = "portable-atomic")]
+pub mod latest;
+mod macros;
+pub mod platform;
+#[cfg(feature = "embedded-hal-async")]
+pub mod polling;
+pub mod protocol;
+pub mod scale;
+pub mod scanner;
+pub mod self_check;
+pub mod shared;
+#[cfg(feature = "std")]
+pub mod sim;
+pub mod stats;
+pub mod suspend;
+pub mod threshold;
+pub mod units;
+
+use protocol::{Action, FrameReader};
+use stats::Accumulator;
+use units::{BridgeOutput, Voltage};
+
 #[doc(hidden)]
 mod private {
     pub trait Sealed {}
+
+    /// Seals [`super::ADSModel`] unless the `unstable-custom-model` feature is
+    /// enabled
+    pub trait SealedModel {}
+
+    #[cfg(feature = "unstable-custom-model")]
+    impl<T> SealedModel for T {}
+}
+
+/// A supported chip in the ADS123x family
+///
+/// This trait is sealed, but its associated constants can be used by generic
+/// downstream code to adapt to the model in use.
+///
+/// With the `unstable-custom-model` feature, downstream crates can implement
+/// it for other converters with a compatible interface. The items needed for
+/// this may change in any release, and the driver panics if a model breaks
+/// their documented constraints.
+pub trait ADSModel: private::SealedModel {
+    /// Resolution of the converter, which is the number of two's complement
+    /// data bits clocked out of DOUT for each conversion (from 1 to 32)
+    const BITS: u32;
+
+    /// Input selected by the mux on this model, which is `()` on
+    /// single-channel models
+    ///
+    /// The [`Default`] channel is the one selected when both mux pins are low.
+    type Channel: Copy + Eq + Default + core::fmt::Debug + 'static;
+
+    /// Every channel on this model, in index order (at most [`MAX_CHANNELS`])
+    const CHANNELS: &'static [Self::Channel];
+
+    /// Position of the channel in per-channel tables (always less than
+    /// [`MAX_CHANNELS`])
+    #[cfg_attr(not(feature = "unstable-custom-model"), doc(hidden))]
+    fn channel_index(channel: Self::Channel) -> usize;
+
+    /// States the A0 and A1 pins must be driven to in order to select the
+    /// channel
+    #[cfg_attr(not(feature = "unstable-custom-model"), doc(hidden))]
+    fn mux_state(channel: Self::Channel) -> (PinState, PinState);
+
+    /// Channel selected by the current (set high) state of the A0 and A1 pins,
+    /// or `None` if the combination doesn't select a valid input
+    #[cfg_attr(not(feature = "unstable-custom-model"), doc(hidden))]
+    fn decode_mux(a0: bool, a1: bool) -> Option<Self::Channel>;
+
+    /// Number of conversions that must be thrown away after switching between
+    /// the two channels before the data is settled
+    #[cfg_attr(not(feature = "unstable-custom-model"), doc(hidden))]
+    fn settling_discards(from: Self::Channel, to: Self::Channel) -> u32;
+
+    /// Largest number of conversions [`Self::settling_discards`] returns for
+    /// any pair of channels, which is used when the previous state of the mux
+    /// is unknown
+    #[cfg_attr(not(feature = "unstable-custom-model"), doc(hidden))]
+    const MAX_SETTLING_DISCARDS: u32;
+
+    /// Reading for an input of `+0.5 * VREF / gain` or more (`0x7FFFFF` on
+    /// 24-bit models)
+    const FULL_SCALE_POSITIVE: i32 = ((1i64 << (Self::BITS - 1)) - 1) as i32;
+
+    /// Reading for an input of `-0.5 * VREF / gain` or less (`-0x800000` on
+    /// 24-bit models)
+    const FULL_SCALE_NEGATIVE: i32 = (-(1i64 << (Self::BITS - 1))) as i32;
+
+    /// Input voltage of one count in nanovolts for a reference of `vref_uv`
+    /// microvolts and the given PGA gain
+    ///
+    /// This is the same scaling [`ADS123X::counts_to_voltage`] applies. At
+    /// high gains one count is only a few nanovolts, hence the float.
+    fn lsb_size_nv(vref_uv: u32, gain: Gain) -> f32 {
+        vref_uv as f32 * 1_000.0 / (gain.factor() as f32 * (1u64 << Self::BITS) as f32)
+    }
+}
+
+/// Constructor for models implemented outside of this crate
+#[cfg(feature = "unstable-custom-model")]
+impl<DOUT, SCLK, PWDN, A0, A1, M> ADS123X<DOUT, SCLK, PWDN, A0, A1, M>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+{
+    /// Creates a driver for a custom model, passing [`NoPin`] for any mux pin
+    /// it doesn't have
+    pub fn new_custom(dout: DOUT, sclk: SCLK, pwdn: PWDN, a0: A0, a1: A1) -> Self {
+        ADS123X::new(dout, sclk, pwdn, a0, a1, NoClock, NoHooks)
+    }
+}
+
+/// Largest number of channels on any supported model
+pub const MAX_CHANNELS: usize = 4;
+
+/// Number of fractional bits in a system gain correction factor
+pub const GAIN_FRACTIONAL_BITS: u32 = 24;
+
+/// System gain correction factor of exactly 1 (gain factors are signed Q8.24
+/// fixed point numbers)
+pub const UNITY_GAIN: i32 = 1 << GAIN_FRACTIONAL_BITS;
+
+/// Number of fractional bits in a linearity correction coefficient
+pub const LINEARITY_FRACTIONAL_BITS: u32 = 48;
+
+/// A conversion read with [`ADS123X::read_calibrated_blocking`] (or
+/// [`ADS123X::read_calibrated`]) alongside the result of applying the stored
+/// corrections to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CalibratedReading {
+    /// Sign extended conversion result without any corrections applied
+    pub raw: i32,
+    /// Result after applying the software offset, system gain, and linearity
+    /// corrections
+    pub corrected: i32,
+}
+
+/// The average of several conversions read with
+/// [`ADS123X::read_oversampled_blocking`] (or [`ADS123X::read_oversampled`]),
+/// with extra fractional bits of resolution
+///
+/// Averaging `N` conversions reduces white noise by a factor of `sqrt(N)`, so
+/// the average carries `log4(N)` (rounded down) more bits of information than
+/// a single conversion: 1 extra bit for 4 conversions, 2 for 16, 3 for 64, and
+/// so on. This only holds as long as the noise is white, which is typically
+/// the case up to averaging times of a few seconds, beyond which drift
+/// dominates ([`stats::AllanDeviation`] shows where exactly).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OversampledReading {
+    /// Average of the corrected conversions in units of `2^-extra_bits` counts
+    pub value: i64,
+    /// Number of fractional bits in `value`
+    pub extra_bits: u32,
+}
+
+impl OversampledReading {
+    /// Averages the sum of `n` conversions, keeping as many fractional bits as
+    /// the number of conversions supports
+    fn from_sum(sum: i64, n: usize) -> Self {
+        let n = n.max(1);
+        let extra_bits = n.ilog2() / 2;
+
+        Self {
+            value: (((sum as i128) << extra_bits) / n as i128) as i64,
+            extra_bits,
+        }
+    }
+
+    /// Returns the average rounded to whole counts
+    pub fn counts(&self) -> i32 {
+        let half = (1i64 << self.extra_bits) >> 1;
+
+        ((self.value + half) >> self.extra_bits) as i32
+    }
+}
+
+/// A conversion read with [`ADS123X::read_with_status_blocking`] (or
+/// [`ADS123X::read_with_status`]) alongside an account of what the driver had
+/// to do to obtain it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReadStatus {
+    pub reading: CalibratedReading,
+    /// Whether the chip was in standby and had to be woken up
+    pub exited_standby: bool,
+    /// Whether the mux had to be switched to a different channel
+    pub channel_changed: bool,
+    /// Number of conversions thrown away while the input settled
+    pub discarded: u32,
+    /// Time spent waiting for DRDY across the discarded conversions and the
+    /// one that was read, in microseconds as measured by the driver's
+    /// [`Clock`]
+    pub drdy_wait_us: u64,
+    /// Time the whole read took, in microseconds as measured by the driver's
+    /// [`Clock`]
+    pub duration_us: u64,
+    /// Number of conversions the chip completed before this read without them
+    /// being read (see [`Metrics::overruns`])
+    pub overruns: u32,
+}
+
+impl ReadStatus {
+    /// Time spent waiting for DRDY across the discarded conversions and the
+    /// one that was read, as measured by the driver's [`Clock`]
+    #[cfg(feature = "fugit")]
+    pub const fn drdy_wait(&self) -> fugit::MicrosDurationU64 {
+        fugit::MicrosDurationU64::from_ticks(self.drdy_wait_us)
+    }
+
+    /// Time the whole read took, as measured by the driver's [`Clock`]
+    #[cfg(feature = "fugit")]
+    pub const fn duration(&self) -> fugit::MicrosDurationU64 {
+        fugit::MicrosDurationU64::from_ticks(self.duration_us)
+    }
+}
+
+/// The state of the interface as seen by the driver, returned by
+/// [`ADS123X::debug_snapshot`] for chasing bring-up problems
+///
+/// SCLK and PWDN can't be read back, so their levels are inferred from the
+/// power state the driver last put the chip into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DebugSnapshot {
+    pub sclk_high: bool,
+    pub pwdn_high: bool,
+    /// Level the A0 pin is set to
+    pub a0_high: bool,
+    /// Level the A1 (or TEMP on the ADS1232) pin is set to
+    pub a1_high: bool,
+    /// Level of DOUT when the snapshot was taken, which is low while a
+    /// conversion is waiting to be read
+    pub dout_high: bool,
+    /// Index of the channel the mux pins select (0 for AIN1, 1 for AIN2, and
+    /// so on), or `None` if they are in an invalid state
+    pub channel: Option<u8>,
+    pub power_state: PowerState,
+    /// Whether the last frame is still waiting for its trailing SCLK pulse
+    /// (see [`TrailingPulse`])
+    pub frame_unterminated: bool,
+}
+
+/// A corrected conversion alongside the time it was read, in microseconds as
+/// measured by the driver's [`Clock`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TimestampedReading {
+    pub value: i32,
+    pub timestamp_us: u64,
+}
+
+/// Outcome of [`ADS123X::capture_until_blocking`] (or
+/// [`ADS123X::capture_until`])
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CaptureSummary {
+    /// Number of readings written to the buffer
+    pub captured: usize,
+    /// Number of conversions that completed between two captured readings
+    /// without being read, estimated from the gaps between their timestamps
+    pub missed: u32,
+}
+
+impl CaptureSummary {
+    /// Returns whether any conversion was missed during the capture
+    pub fn missed_any(&self) -> bool {
+        self.missed > 0
+    }
+
+    /// Counts a reading taken at `timestamp_us`, and the conversions missed
+    /// since the previous one if a whole period or more was skipped
+    fn record(&mut self, timestamp_us: u64, previous_us: Option<u64>, period_us: u32) {
+        self.captured += 1;
+
+        let (Some(previous_us), Some(period_us)) = (previous_us, NonZeroU64::new(period_us as u64))
+        else {
+            return;
+        };
+
+        // Round the gap to whole periods, so that jitter in when each frame
+        // finished being shifted out isn't counted
+        let periods = (timestamp_us.saturating_sub(previous_us) + period_us.get() / 2) / period_us;
+        self.missed = self
+            .missed
+            .saturating_add(periods.saturating_sub(1).min(u32::MAX as u64) as u32);
+    }
+}
+
+/// Placeholder for mux pins that don't exist on single-channel models such as
+/// the ADS1130 and ADS1131
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NoPin;
+
+impl embedded_hal::digital::ErrorType for NoPin {
+    type Error = core::convert::Infallible;
+}
+
+impl OutputPin for NoPin {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl StatefulOutputPin for NoPin {
+    fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+/// Output data rate selected by the SPEED pin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Speed {
+    /// SPEED low, 10 samples per second
+    Sps10,
+    /// SPEED high, 80 samples per second
+    Sps80,
+}
+
+impl Speed {
+    /// Time between consecutive conversions at this data rate in microseconds
+    pub const fn conversion_period_us(self) -> u32 {
+        match self {
+            Speed::Sps10 => 100_000,
+            Speed::Sps80 => 12_500,
+        }
+    }
+
+    /// Time between consecutive conversions at this data rate
+    #[cfg(feature = "fugit")]
+    pub const fn conversion_period(self) -> fugit::MicrosDurationU32 {
+        fugit::MicrosDurationU32::from_ticks(self.conversion_period_us())
+    }
+
+    /// Time from waking up out of standby or power-down until the first
+    /// conversion is ready in microseconds, which is dominated by the 4
+    /// conversion periods the digital filter takes to settle
+    pub const fn wake_time_us(self) -> u32 {
+        4 * self.conversion_period_us()
+    }
+
+    /// Time from waking up out of standby or power-down until the first
+    /// conversion is ready
+    #[cfg(feature = "fugit")]
+    pub const fn wake_time(self) -> fugit::MicrosDurationU32 {
+        fugit::MicrosDurationU32::from_ticks(self.wake_time_us())
+    }
+
+    /// Longest time [`ADS123X::wake_blocking`] (and [`ADS123X::wake`]) waits
+    /// for the first conversion, which is the wake time plus a 50% margin for
+    /// oscillator tolerance in microseconds
+    pub const fn wake_timeout_us(self) -> u32 {
+        self.wake_time_us() + self.wake_time_us() / 2
+    }
+
+    /// Longest time [`ADS123X::wake_blocking`] (and [`ADS123X::wake`]) waits
+    /// for the first conversion
+    #[cfg(feature = "fugit")]
+    pub const fn wake_timeout(self) -> fugit::MicrosDurationU32 {
+        fugit::MicrosDurationU32::from_ticks(self.wake_timeout_us())
+    }
+
+    /// Time an offset calibration takes from the last SCLK pulse until the
+    /// next conversion is ready in microseconds, which is about 8 conversion
+    /// periods
+    pub const fn calibration_time_us(self) -> u32 {
+        8 * self.conversion_period_us()
+    }
+
+    /// Time an offset calibration takes from the last SCLK pulse until the
+    /// next conversion is ready
+    #[cfg(feature = "fugit")]
+    pub const fn calibration_time(self) -> fugit::MicrosDurationU32 {
+        fugit::MicrosDurationU32::from_ticks(self.calibration_time_us())
+    }
+
+    /// Longest time [`ADS123X::calibrate_offset_blocking`] (and
+    /// [`ADS123X::calibrate_offset`]) waits for the calibration to complete,
+    /// which is the calibration time plus a 50% margin for oscillator
+    /// tolerance in microseconds
+    pub const fn calibration_timeout_us(self) -> u32 {
+        self.calibration_time_us() + self.calibration_time_us() / 2
+    }
+
+    /// Longest time [`ADS123X::calibrate_offset_blocking`] (and
+    /// [`ADS123X::calibrate_offset`]) waits for the calibration to complete
+    #[cfg(feature = "fugit")]
+    pub const fn calibration_timeout(self) -> fugit::MicrosDurationU32 {
+        fugit::MicrosDurationU32::from_ticks(self.calibration_timeout_us())
+    }
+}
+
+/// Errors that can be returned by the driver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+#[non_exhaustive]
+pub enum Error {
+    /// The readings making up an averaged measurement varied by more than the
+    /// allowed amount, e.g. because the input was vibrating
+    Unstable,
+    /// DRDY didn't go low within [`Speed::wake_timeout_us`] of waking the chip
+    /// from standby or power-down, e.g. because it isn't powered or PWDN is
+    /// stuck low
+    WakeTimeout,
+    /// An integer didn't correspond to any channel on the model
+    InvalidChannel,
+    /// The bytes passed to [`calibration::Calibration::from_bytes`] are not a
+    /// calibration blob (e.g. erased flash) or were written by an incompatible
+    /// version of the driver, or a calibration would divide by zero (e.g. a
+    /// span whose reference weight didn't change the reading)
+    InvalidCalibration,
+    /// The bytes passed to [`calibration::Calibration::from_bytes`] look like a
+    /// calibration blob but failed the CRC check
+    CorruptCalibration,
+    /// A scale factor is so extreme that converting readings with it would
+    /// overflow the result
+    Overflow,
+    /// DOUT stayed high from some point of a frame to its end and the chip
+    /// didn't produce another conversion in time, so it most likely browned
+    /// out or was powered down while the frame was being shifted out (only
+    /// detected by [`ADS123X::read_checked_blocking`] and its async twin)
+    AbortedFrame,
+    /// Several consecutive frames were all zeros and DOUT never went high
+    /// while they were read, which points at a wiring or power fault rather
+    /// than a real input (see [`ADS123X::wiring_fault`])
+    WiringFault,
+    /// DRDY didn't go low within [`Speed::calibration_timeout_us`] of starting
+    /// an offset calibration
+    CalibrationTimeout,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Error::Unstable => "readings varied by more than allowed",
+            Error::WakeTimeout => "chip did not report a conversion after waking",
+            Error::InvalidChannel => "no such channel on this model",
+            Error::InvalidCalibration => "invalid calibration",
+            Error::CorruptCalibration => "calibration failed its CRC check",
+            Error::Overflow => "scale factor would overflow the result",
+            Error::AbortedFrame => "chip stopped responding while a frame was shifted out",
+            Error::WiringFault => "consecutive frames were all zeros with DOUT stuck low",
+            Error::CalibrationTimeout => "offset calibration did not complete in time",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Probable wiring or power fault behind a run of degenerate frames
+///
+/// A quiet input can legitimately read as the same code over and over, so a
+/// frame only counts towards a fault if DOUT also looked stuck while it was
+/// read, i.e. it never went high, neither while waiting for DRDY nor after a
+/// trailing pulse. A DOUT that is stuck high never signals DRDY in the first
+/// place, so it shows up as a timeout (e.g. [`Error::WakeTimeout`]) instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WiringFault {
+    /// Frames were all zeros and DOUT never went high, e.g. because DOUT is
+    /// shorted to ground or the chip is unpowered and DOUT is pulled low
+    StuckLow,
+}
+
+/// Number of consecutive degenerate frames after which
+/// [`ADS123X::wiring_fault`] reports a fault
+pub const WIRING_FAULT_FRAMES: u32 = 4;
+
+/// PGA gain selected by the GAIN0 and GAIN1 pins
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Gain {
+    X1,
+    X2,
+    X64,
+    X128,
 }
 
-pub trait ADSModel: private::Sealed {}
+impl Gain {
+    /// Factor the input signal is multiplied by
+    pub const fn factor(self) -> u32 {
+        match self {
+            Gain::X1 => 1,
+            Gain::X2 => 2,
+            Gain::X64 => 64,
+            Gain::X128 => 128,
+        }
+    }
+}
+
+/// What happens to DOUT once a frame has been shifted out
+///
+/// After the last data bit DOUT keeps the level of the LSB until the next
+/// conversion is ready. An extra SCLK pulse forces it high, so that a low LSB
+/// isn't mistaken for DRDY, but boards that detect DRDY with external edge
+/// detection circuitry may want DOUT to keep reflecting DRDY instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TrailingPulse {
+    /// Pulse SCLK right after every frame (25 pulses per read on 24-bit
+    /// models)
+    #[default]
+    Always,
+    /// Leave DOUT alone after a frame and pulse SCLK at the start of the next
+    /// one instead, before waiting for DRDY
+    ///
+    /// The next read has to start before the next conversion completes, as the
+    /// chip would otherwise take the deferred pulse as the first of the new
+    /// frame. This suits loops that start each read right after the previous
+    /// one, rather than in response to DRDY.
+    Deferred,
+    /// Never pulse SCLK after a frame, and wait for DOUT to go high before
+    /// waiting for DRDY in the next one
+    ///
+    /// DOUT only goes high for a short moment before each conversion after a
+    /// low LSB, so this relies on the wait catching the edge (e.g. through an
+    /// interrupt) and may otherwise skip a conversion.
+    Never,
+}
+
+/// Board-level settings the driver has no way of detecting on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Config {
+    /// Voltage across REFP and REFN in microvolts
+    pub vref_uv: u32,
+    /// PGA gain strapped on the GAIN0 and GAIN1 pins
+    pub gain: Gain,
+    /// Data rate strapped on the SPEED pin
+    ///
+    /// This also sets how long [`ADS123X::read_checked_blocking`] waits to
+    /// confirm a frame ending in a one, which is up to one and a half
+    /// conversion periods for about half of all checked reads.
+    pub speed: Speed,
+    /// What happens to DOUT after a frame
+    pub trailing_pulse: TrailingPulse,
+}
+
+impl Default for Config {
+    /// A 5 V reference with GAIN0, GAIN1, and SPEED all tied low (a gain of 1
+    /// at 10 SPS)
+    fn default() -> Self {
+        Self {
+            vref_uv: 5_000_000,
+            gain: Gain::X1,
+            speed: Speed::Sps10,
+            trailing_pulse: TrailingPulse::Always,
+        }
+    }
+}
+
+/// A monotonic time source used to timestamp events and measure durations
+///
+/// This is implemented for any `FnMut() -> u64` closure, so a HAL's global
+/// time base can be plugged in directly (e.g.
+/// `|| embassy_time::Instant::now().as_micros()`).
+pub trait Clock {
+    /// Returns the number of microseconds elapsed since an arbitrary, fixed
+    /// point in time
+    fn now_us(&mut self) -> u64;
+
+    /// Returns the current time as a `fugit` instant with microsecond ticks
+    #[cfg(feature = "fugit")]
+    fn now(&mut self) -> fugit::TimerInstantU64<1_000_000> {
+        fugit::TimerInstantU64::from_ticks(self.now_us())
+    }
+}
+
+impl<F: FnMut() -> u64> Clock for F {
+    fn now_us(&mut self) -> u64 {
+        self()
+    }
+}
+
+/// Default clock for drivers that haven't been given one, which reports every
+/// timestamp as zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NoClock;
+
+impl Clock for NoClock {
+    fn now_us(&mut self) -> u64 {
+        0
+    }
+}
+
+/// Callbacks invoked by the driver at points where applications commonly need
+/// to run code of their own
+///
+/// Every method has an empty default implementation, so implementors only need
+/// to override the ones they are interested in. This is also implemented for
+/// any `FnMut()` closure, which is called from [`Self::on_busy_wait`].
+///
+/// Besides keeping the rest of the system alive during long operations, the
+/// hooks can instrument the driver, e.g. to blink an LED on every conversion or
+/// count errors in application metrics.
+pub trait AdsHooks {
+    /// Called roughly once per millisecond while a blocking function is busy
+    /// waiting for DRDY (or sleeping between duty-cycled reads), e.g. to pet a
+    /// watchdog, poll a communications stack, or yield to a cooperative
+    /// scheduler
+    ///
+    /// Conversions take 100 ms at 10 SPS, so without this a blocking read
+    /// starves everything else running on the same core.
+    fn on_busy_wait(&mut self) {}
+
+    /// Called after every step of a long operation (each conversion, including
+    /// the ones thrown away while an input settles or averaged during a
+    /// calibration, and the completion of standby entry or an offset
+    /// calibration), whether blocking or async
+    fn on_progress(&mut self) {}
+
+    /// Called when the driver sets SCLK low to start waiting for a conversion
+    fn on_conversion_started(&mut self) {}
+
+    /// Called with the sign extended result of every conversion once it has
+    /// been shifted out, before any corrections are applied
+    fn on_conversion_completed(&mut self, raw: i32) {
+        let _ = raw;
+    }
+
+    /// Called once a calibration has been performed
+    fn on_calibration(&mut self, kind: CalibrationKind) {
+        let _ = kind;
+    }
+
+    /// Called when a freshly read conversion shows that `missed` conversions
+    /// before it were never read (see [`Metrics::overruns`])
+    fn on_overrun(&mut self, missed: u32) {
+        let _ = missed;
+    }
+
+    /// Called when a conversion is about to be read `latency_us` microseconds
+    /// after DRDY went low, which is more than the limit set with
+    /// [`ADS123X::set_max_read_latency_us`]
+    fn on_read_latency_exceeded(&mut self, latency_us: u64) {
+        let _ = latency_us;
+    }
+
+    /// Called when a driver function is about to return an error
+    fn on_error(&mut self, error: Error) {
+        let _ = error;
+    }
+}
+
+/// A calibration reported to [`AdsHooks::on_calibration`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CalibrationKind {
+    /// The chip's internal offset calibration
+    Offset,
+    /// A software offset measured by [`ADS123X::measure_software_offset_blocking`]
+    /// (or [`ADS123X::measure_software_offset`])
+    SoftwareOffset,
+    /// A system gain calibrated by [`ADS123X::calibrate_system_gain_blocking`]
+    /// (or [`ADS123X::calibrate_system_gain`])
+    SystemGain,
+}
+
+impl<F: FnMut()> AdsHooks for F {
+    fn on_busy_wait(&mut self) {
+        self()
+    }
+}
+
+/// Default hooks for drivers that haven't been given any, which do nothing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NoHooks;
+
+impl AdsHooks for NoHooks {}
+
+/// A watchdog that must be fed periodically to prevent it from resetting the
+/// device
+///
+/// This is implemented for any `FnMut()` closure. Wrap one in
+/// [`WatchdogHooks`] (or use [`ADS123X::with_watchdog`]) to have the driver
+/// feed it throughout operations that take hundreds of milliseconds, such as
+/// entering standby, settling after a switch to the temperature sensor, or
+/// calibrating.
+pub trait Watchdog {
+    fn feed(&mut self);
+}
+
+impl<F: FnMut()> Watchdog for F {
+    fn feed(&mut self) {
+        self()
+    }
+}
+
+/// Hooks that feed a [`Watchdog`] while waiting for DRDY and after every step
+/// of a long operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WatchdogHooks<W>(pub W);
+
+impl<W: Watchdog> AdsHooks for WatchdogHooks<W> {
+    fn on_busy_wait(&mut self) {
+        self.0.feed();
+    }
+
+    fn on_progress(&mut self) {
+        self.0.feed();
+    }
+}
+
+/// Interval at which blocking functions poll DOUT while waiting for DRDY
+const DRDY_POLL_INTERVAL_US: u32 = 10;
+
+/// Interval at which [`AdsHooks::on_busy_wait`] is called while waiting
+const BUSY_WAIT_HOOK_INTERVAL_US: u32 = 1_000;
+
+/// Number of times DOUT is polled for the update that precedes a conversion
+/// between calls to [`AdsHooks::on_busy_wait`]
+const UPDATE_POLLS_PER_HOOK: u32 = 1_000;
+
+/// Time to wait after switching channels before DRDY is valid
+const DRDY_SETUP_US: u32 = 50;
+
+/// Datasheet minimum for both the SCLK high and low time
+#[cfg(feature = "timing-check")]
+const SCLK_MIN_PHASE_NS: u64 = 100;
+
+/// SCLK timing used while shifting data out of the chip
+///
+/// The durations are associated constants so that the delays are resolved at
+/// compile time, and a profile with a zero duration skips the corresponding
+/// delay call entirely. Use [`Timing`] to define a profile.
+///
+/// [`ADS123X::calibrate_timing_blocking`] can shorten the delays at runtime by
+/// the time the GPIO writes themselves take.
+pub trait TimingProfile {
+    /// Time SCLK is held high for each bit, with DOUT sampled half way through
+    const SCLK_HIGH_NS: u32;
+    /// Time SCLK is held low between bits
+    const SCLK_LOW_NS: u32;
+}
+
+/// A [`TimingProfile`] with the given SCLK high and low times in nanoseconds
+///
+/// The datasheet requires both to be at least 100 ns (the [`DefaultTiming`]),
+/// but MCUs whose GPIO writes already take that long can get away with less.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Timing<const SCLK_HIGH_NS: u32, const SCLK_LOW_NS: u32>;
+
+impl<const SCLK_HIGH_NS: u32, const SCLK_LOW_NS: u32> TimingProfile
+    for Timing<SCLK_HIGH_NS, SCLK_LOW_NS>
+{
+    const SCLK_HIGH_NS: u32 = SCLK_HIGH_NS;
+    const SCLK_LOW_NS: u32 = SCLK_LOW_NS;
+}
+
+/// Timing used by drivers that haven't been given a profile, which meets the
+/// datasheet minimums
+pub type DefaultTiming = Timing<100, 100>;
+
+/// Number of SCLK pulses timed by [`ADS123X::calibrate_timing_blocking`]
+const TIMING_CALIBRATION_PULSES: u64 = 1_000;
+
+/// Number of recent conversions [`ADS123X::measured_sample_rate`] is computed
+/// from
+const RATE_WINDOW: usize = 8;
+
+/// Timestamps of the most recent conversions that were read back to back
+#[derive(Debug, Clone, Copy, Default)]
+struct RateMeter {
+    timestamps_us: [u64; RATE_WINDOW],
+    len: usize,
+    next: usize,
+}
+
+impl RateMeter {
+    fn push(&mut self, now_us: u64) {
+        self.timestamps_us[self.next] = now_us;
+        self.next = (self.next + 1) % RATE_WINDOW;
+        self.len = (self.len + 1).min(RATE_WINDOW);
+    }
+
+    /// Forgets the timestamps, e.g. because the chip restarted its conversion
+    /// cycle and the next one won't follow a full period after the last
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Average rate over the window in thousandths of a sample per second
+    fn rate_millihertz(&self) -> Option<u32> {
+        let newest = self.timestamps_us[(self.next + RATE_WINDOW - 1) % RATE_WINDOW];
+        let oldest = self.timestamps_us[(self.next + RATE_WINDOW - self.len) % RATE_WINDOW];
+        let span_us = newest.checked_sub(oldest).filter(|&span_us| span_us > 0)?;
+        let intervals = self.len.checked_sub(1)? as u64;
+
+        Some((intervals * 1_000_000_000 / span_us).min(u32::MAX as u64) as u32)
+    }
+}
+
+/// Health counters maintained by the driver, intended to be reported upstream
+/// as telemetry by long-running devices
+///
+/// Counters wrap around on overflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Metrics {
+    /// Number of conversions clocked out of the chip, including the ones thrown
+    /// away while the input settles and the ones preceding a calibration
+    pub conversions: u32,
+    /// Number of offset calibrations that have been performed
+    pub calibrations: u32,
+    /// Longest time spent waiting for DRDY to go low before a conversion could
+    /// be read, in microseconds as measured by the driver's [`Clock`]
+    pub max_drdy_latency_us: u64,
+    /// Total time spent waiting for DRDY to go low, in microseconds as
+    /// measured by the driver's [`Clock`]
+    pub total_drdy_wait_us: u64,
+    /// Number of times the mux pins were found in a combination that doesn't
+    /// select a valid input (e.g. because something else drove them) and had
+    /// to be reset before a read
+    pub invalid_mux_states: u32,
+    /// Number of times waking the chip failed with [`Error::WakeTimeout`]
+    pub wake_timeouts: u32,
+    /// Number of offset calibrations that failed with
+    /// [`Error::CalibrationTimeout`]
+    pub calibration_timeouts: u32,
+    /// Time the most recent successful offset calibration took, in
+    /// microseconds as measured by the driver's [`Clock`]
+    pub last_calibration_us: u64,
+    /// Time the most recent read took from setting SCLK low until the frame
+    /// had been shifted out, in microseconds as measured by the driver's
+    /// [`Clock`]
+    ///
+    /// When reading continuously this should stay close to
+    /// [`Speed::conversion_period_us`], and a drift away from it points at a
+    /// problem with the chip's clock or supply.
+    pub last_conversion_us: u64,
+    /// Number of times the `timing-check` feature found a read or channel
+    /// switch that violated the datasheet timing (or couldn't be checked
+    /// because the [`Clock`] stepped back), which is always zero without it
+    pub timing_violations: u32,
+    /// Number of frames rejected with [`Error::AbortedFrame`]
+    pub aborted_frames: u32,
+    /// Number of runs of [`WIRING_FAULT_FRAMES`] or more degenerate frames
+    pub wiring_faults: u32,
+    /// Number of conversions the chip completed that were never read because
+    /// the reads didn't keep up with the data rate, which leaves gaps in a
+    /// continuously logged timeline
+    ///
+    /// This is estimated from the driver's [`Clock`] and the nominal
+    /// [`Speed::conversion_period_us`], so it stays zero with [`NoClock`] and
+    /// may be off by one now and then when the reads are just about a whole
+    /// period apart. Channel switches, calibrations, and standby restart the
+    /// count rather than adding to it.
+    pub overruns: u32,
+    /// Number of conversions that were read later after DRDY went low than
+    /// allowed by [`ADS123X::set_max_read_latency_us`]
+    pub read_latency_violations: u32,
+}
+
+impl Metrics {
+    /// Longest time spent waiting for DRDY to go low before a conversion could
+    /// be read, as measured by the driver's [`Clock`]
+    #[cfg(feature = "fugit")]
+    pub const fn max_drdy_latency(&self) -> fugit::MicrosDurationU64 {
+        fugit::MicrosDurationU64::from_ticks(self.max_drdy_latency_us)
+    }
+
+    /// Total time spent waiting for DRDY to go low, as measured by the
+    /// driver's [`Clock`]
+    #[cfg(feature = "fugit")]
+    pub const fn total_drdy_wait(&self) -> fugit::MicrosDurationU64 {
+        fugit::MicrosDurationU64::from_ticks(self.total_drdy_wait_us)
+    }
+
+    /// Time the most recent read took from setting SCLK low until the frame
+    /// had been shifted out, as measured by the driver's [`Clock`]
+    #[cfg(feature = "fugit")]
+    pub const fn last_conversion(&self) -> fugit::MicrosDurationU64 {
+        fugit::MicrosDurationU64::from_ticks(self.last_conversion_us)
+    }
+
+    /// Time the most recent successful offset calibration took, as measured
+    /// by the driver's [`Clock`]
+    #[cfg(feature = "fugit")]
+    pub const fn last_calibration(&self) -> fugit::MicrosDurationU64 {
+        fugit::MicrosDurationU64::from_ticks(self.last_calibration_us)
+    }
+}
+
+/// The power state the driver last put the chip into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PowerState {
+    /// Converting continuously
+    Active,
+    /// In standby mode after [`ADS123X::enter_standby_blocking`]
+    Standby,
+    /// Held in power-down by PWDN, e.g. after [`ADS123X::power_down`]
+    PoweredDown,
+}
+
+/// Cumulative time the chip has spent in each [`PowerState`], in microseconds
+/// as measured by the driver's [`Clock`]
+///
+/// Multiplying each of these by the supply current of the corresponding state
+/// gives the charge drawn by the converter, so that a battery budget can be
+/// checked against the application's actual duty cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PowerProfile {
+    pub active_us: u64,
+    pub standby_us: u64,
+    pub powered_down_us: u64,
+}
+
+impl PowerProfile {
+    /// Total time covered by the profile in microseconds
+    pub const fn total_us(&self) -> u64 {
+        self.active_us + self.standby_us + self.powered_down_us
+    }
+
+    /// Time spent converting
+    #[cfg(feature = "fugit")]
+    pub const fn active(&self) -> fugit::MicrosDurationU64 {
+        fugit::MicrosDurationU64::from_ticks(self.active_us)
+    }
+
+    /// Time spent in standby
+    #[cfg(feature = "fugit")]
+    pub const fn standby(&self) -> fugit::MicrosDurationU64 {
+        fugit::MicrosDurationU64::from_ticks(self.standby_us)
+    }
+
+    /// Time spent powered down
+    #[cfg(feature = "fugit")]
+    pub const fn powered_down(&self) -> fugit::MicrosDurationU64 {
+        fugit::MicrosDurationU64::from_ticks(self.powered_down_us)
+    }
+
+    /// Total time covered by the profile
+    #[cfg(feature = "fugit")]
+    pub const fn total(&self) -> fugit::MicrosDurationU64 {
+        fugit::MicrosDurationU64::from_ticks(self.total_us())
+    }
+
+    /// Fraction of the total time spent active, in parts per million, or
+    /// `None` if no time has been covered yet
+    pub const fn active_ppm(&self) -> Option<u32> {
+        let total = self.total_us();
+
+        if total == 0 {
+            return None;
+        }
+
+        Some((self.active_us as u128 * 1_000_000 / total as u128) as u32)
+    }
+}
 
-pub struct ADS123X<DOUT, SCLK, PWDN, A0, A1, M>
+pub struct ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C = NoClock, H = NoHooks, T = DefaultTiming>
 where
     DOUT: InputPin,
     SCLK: OutputPin,
@@ -25,16 +1025,39 @@ where
     A0: StatefulOutputPin,
     A1: StatefulOutputPin,
     M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
 {
     dout: DOUT,
     sclk: SCLK,
     pwdn: PWDN,
     a0: A0,
     a1: A1,
+    clock: C,
+    hooks: H,
+    metrics: Metrics,
+    power_state: PowerState,
+    power_state_since_us: u64,
+    power_profile: PowerProfile,
+    sclk_write_ns: u32,
+    frame_unterminated: bool,
+    rate: RateMeter,
+    degenerate: Option<(WiringFault, u32)>,
+    /// Estimated time DRDY went low for the last conversion that was read, or
+    /// `None` if the conversion cycle has restarted since
+    drdy_anchor_us: Option<u64>,
+    max_read_latency_us: Option<u32>,
+    offsets: [i32; MAX_CHANNELS],
+    gains: [i32; MAX_CHANNELS],
+    linearity: [i32; MAX_CHANNELS],
+    config: Config,
+    default_channel: M::Channel,
     _model: PhantomData<M>,
+    _timing: PhantomData<T>,
 }
 
-impl<DOUT, SCLK, PWDN, A0, A1, M> ADS123X<DOUT, SCLK, PWDN, A0, A1, M>
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
 where
     DOUT: InputPin,
     SCLK: OutputPin,
@@ -42,26 +1065,855 @@ where
     A0: StatefulOutputPin,
     A1: StatefulOutputPin,
     M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
 {
-    fn new(dout: DOUT, sclk: SCLK, pwdn: PWDN, a0: A0, a1: A1) -> Self {
+    fn new(dout: DOUT, sclk: SCLK, pwdn: PWDN, a0: A0, a1: A1, mut clock: C, hooks: H) -> Self {
         Self {
+            power_state: PowerState::Active,
+            power_state_since_us: clock.now_us(),
+            power_profile: PowerProfile::default(),
+            sclk_write_ns: 0,
+            frame_unterminated: false,
+            rate: RateMeter::default(),
+            degenerate: None,
+            drdy_anchor_us: None,
+            max_read_latency_us: None,
             dout,
             sclk,
             pwdn,
             a0,
             a1,
+            clock,
+            hooks,
+            metrics: Metrics::default(),
+            offsets: [0; MAX_CHANNELS],
+            gains: [UNITY_GAIN; MAX_CHANNELS],
+            linearity: [0; MAX_CHANNELS],
+            config: Config::default(),
+            default_channel: M::Channel::default(),
             _model: PhantomData,
+            _timing: PhantomData,
         }
     }
 
-    /// Sets PWDN low, waits for the AVDD voltage to stabilize, then pulses PWDN
-    /// once before setting it high
-    pub fn reset_blocking(&mut self, delay: &mut impl DelayNs) {
-        self.pwdn.set_low().unwrap();
+    /// Replaces the clock used by the driver to timestamp events and measure
+    /// durations
+    ///
+    /// Drivers are constructed with [`NoClock`], which reports every timestamp
+    /// as zero.
+    pub fn with_clock<C2: Clock>(
+        mut self,
+        mut clock: C2,
+    ) -> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C2, H, T> {
+        // Close off the current power state with the old clock and continue it
+        // with the new one
+        self.update_power_profile();
+
+        ADS123X {
+            power_state: self.power_state,
+            power_state_since_us: clock.now_us(),
+            power_profile: self.power_profile,
+            sclk_write_ns: self.sclk_write_ns,
+            frame_unterminated: self.frame_unterminated,
+            rate: self.rate,
+            degenerate: self.degenerate,
+            drdy_anchor_us: self.drdy_anchor_us,
+            max_read_latency_us: self.max_read_latency_us,
+            dout: self.dout,
+            sclk: self.sclk,
+            pwdn: self.pwdn,
+            a0: self.a0,
+            a1: self.a1,
+            clock,
+            hooks: self.hooks,
+            metrics: self.metrics,
+            offsets: self.offsets,
+            gains: self.gains,
+            linearity: self.linearity,
+            config: self.config,
+            default_channel: self.default_channel,
+            _model: PhantomData,
+            _timing: PhantomData,
+        }
+    }
 
-        // Wait for AVDD to stabilize (we can't easily measure this so we just
-        // wait for a predefined amount of time that should be fine)
-        delay.delay_us(50);
+    /// Replaces the hooks invoked by the driver, e.g. with a closure that is
+    /// called while blocking functions wait for a conversion
+    ///
+    /// Drivers are constructed with [`NoHooks`], which do nothing.
+    pub fn with_hooks<H2: AdsHooks>(
+        self,
+        hooks: H2,
+    ) -> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H2, T> {
+        ADS123X {
+            dout: self.dout,
+            sclk: self.sclk,
+            pwdn: self.pwdn,
+            a0: self.a0,
+            a1: self.a1,
+            clock: self.clock,
+            hooks,
+            metrics: self.metrics,
+            power_state: self.power_state,
+            power_state_since_us: self.power_state_since_us,
+            power_profile: self.power_profile,
+            sclk_write_ns: self.sclk_write_ns,
+            frame_unterminated: self.frame_unterminated,
+            rate: self.rate,
+            degenerate: self.degenerate,
+            drdy_anchor_us: self.drdy_anchor_us,
+            max_read_latency_us: self.max_read_latency_us,
+            offsets: self.offsets,
+            gains: self.gains,
+            linearity: self.linearity,
+            config: self.config,
+            default_channel: self.default_channel,
+            _model: PhantomData,
+            _timing: PhantomData,
+        }
+    }
+
+    /// Replaces the hooks invoked by the driver with [`WatchdogHooks`] that feed
+    /// the given watchdog
+    pub fn with_watchdog<W: Watchdog>(
+        self,
+        watchdog: W,
+    ) -> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, WatchdogHooks<W>, T> {
+        self.with_hooks(WatchdogHooks(watchdog))
+    }
+
+    /// Replaces the SCLK timing profile used while shifting data out, e.g.
+    /// `ads.with_timing::<Timing<50, 50>>()`
+    pub fn with_timing<T2: TimingProfile>(self) -> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T2> {
+        ADS123X {
+            power_state: self.power_state,
+            power_state_since_us: self.power_state_since_us,
+            power_profile: self.power_profile,
+            sclk_write_ns: self.sclk_write_ns,
+            frame_unterminated: self.frame_unterminated,
+            rate: self.rate,
+            degenerate: self.degenerate,
+            drdy_anchor_us: self.drdy_anchor_us,
+            max_read_latency_us: self.max_read_latency_us,
+            dout: self.dout,
+            sclk: self.sclk,
+            pwdn: self.pwdn,
+            a0: self.a0,
+            a1: self.a1,
+            clock: self.clock,
+            hooks: self.hooks,
+            metrics: self.metrics,
+            offsets: self.offsets,
+            gains: self.gains,
+            linearity: self.linearity,
+            config: self.config,
+            default_channel: self.default_channel,
+            _model: PhantomData,
+            _timing: PhantomData,
+        }
+    }
+
+    /// Returns the hooks invoked by the driver
+    pub fn hooks_mut(&mut self) -> &mut H {
+        &mut self.hooks
+    }
+
+    /// Replaces the board-level configuration of the driver
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
+    /// Borrows the DOUT pin, e.g. to enable a pull-up or route it to an
+    /// external interrupt
+    ///
+    /// The driver expects every pin to be usable as it was given once the
+    /// borrow ends. SCLK in particular must be left at the level it was found
+    /// at, as holding it high puts the chip into standby.
+    pub fn dout_pin(&mut self) -> &mut DOUT {
+        &mut self.dout
+    }
+
+    /// Borrows the SCLK pin, see [`Self::dout_pin`]
+    pub fn sclk_pin(&mut self) -> &mut SCLK {
+        &mut self.sclk
+    }
+
+    /// Borrows the PWDN pin, see [`Self::dout_pin`]
+    pub fn pwdn_pin(&mut self) -> &mut PWDN {
+        &mut self.pwdn
+    }
+
+    /// Borrows the A0 mux pin, see [`Self::dout_pin`]
+    ///
+    /// The selected channel is read back from the mux pins before every read,
+    /// so changing their levels only costs a channel switch.
+    pub fn a0_pin(&mut self) -> &mut A0 {
+        &mut self.a0
+    }
+
+    /// Borrows the A1 (or TEMP on the ADS1232) mux pin, see [`Self::a0_pin`]
+    pub fn a1_pin(&mut self) -> &mut A1 {
+        &mut self.a1
+    }
+
+    /// Returns the channel read by [`Self::read_default_blocking`] (and
+    /// [`Self::read_default`])
+    pub fn default_channel(&self) -> M::Channel {
+        self.default_channel
+    }
+
+    /// Sets the channel read by [`Self::read_default_blocking`] (and
+    /// [`Self::read_default`]), which is initially the first input
+    pub fn set_default_channel(&mut self, channel: M::Channel) {
+        self.default_channel = channel;
+    }
+
+    /// Converts a (corrected) reading to the input-referred voltage using the
+    /// reference voltage and PGA gain from the [`Config`]
+    ///
+    /// A full-scale reading corresponds to an input of `±0.5 * VREF / gain`.
+    pub fn counts_to_voltage(&self, counts: i32) -> Voltage {
+        self.counts_to_voltage_wide(counts as i64)
+    }
+
+    /// Same as [`Self::counts_to_voltage`] for a reading read with
+    /// [`Self::read_wide_blocking`] (or [`Self::read_wide`]), which may lie
+    /// beyond the range of an i32
+    pub fn counts_to_voltage_wide(&self, counts: i64) -> Voltage {
+        let vref_nv = self.config.vref_uv as i128 * 1_000;
+        let full_scale = (self.config.gain.factor() as i128) << M::BITS;
+
+        Voltage::from_nanovolts(units::saturate(counts as i128 * vref_nv / full_scale))
+    }
+
+    /// Converts a (corrected) reading to a fraction of full scale in Q31
+    /// format, so that `i32::MIN` and `i32::MAX` correspond to the negative
+    /// and positive full-scale readings
+    ///
+    /// This is convenient for generic DSP code that works on normalized
+    /// samples rather than voltages. Readings beyond full scale (e.g. after a
+    /// gain correction) saturate.
+    pub fn counts_to_fraction(&self, counts: i32) -> i32 {
+        let shift = 32 - M::BITS;
+
+        ((counts as i64) << shift).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+
+    /// Converts a (corrected) reading to a fraction of full scale between -1.0
+    /// and 1.0
+    ///
+    /// Unlike [`Self::counts_to_fraction`], readings beyond full scale are
+    /// passed through unchanged.
+    pub fn counts_to_fraction_f32(&self, counts: i32) -> f32 {
+        counts as f32 / -(M::FULL_SCALE_NEGATIVE as f32)
+    }
+
+    /// Converts a (corrected) reading to the output of a bridge sensor excited
+    /// with `excitation_uv` microvolts, using the reference voltage and PGA
+    /// gain from the [`Config`]
+    ///
+    /// For the usual ratiometric wiring, where the bridge excitation also
+    /// serves as the reference, pass [`Config::vref_uv`] as the excitation.
+    pub fn counts_to_bridge_output(&self, counts: i32, excitation_uv: u32) -> BridgeOutput {
+        let full_scale = (self.config.gain.factor() as i128) << M::BITS;
+        let nv_per_v = counts as i128 * self.config.vref_uv as i128 * 1_000_000_000
+            / (full_scale * excitation_uv.max(1) as i128);
+
+        BridgeOutput::from_nanovolts_per_volt(nv_per_v as i64)
+    }
+
+    /// Converts the output of a bridge sensor excited with `excitation_uv`
+    /// microvolts to the reading it produces (saturated to the range of an
+    /// `i32`), e.g. to derive a span from a load cell's rated output
+    ///
+    /// This is the inverse of [`Self::counts_to_bridge_output`].
+    pub fn bridge_output_to_counts(&self, output: BridgeOutput, excitation_uv: u32) -> i32 {
+        let full_scale = (self.config.gain.factor() as i128) << M::BITS;
+        let counts = output.nanovolts_per_volt() as i128 * excitation_uv as i128 * full_scale
+            / (self.config.vref_uv.max(1) as i128 * 1_000_000_000);
+
+        counts.clamp(i32::MIN as i128, i32::MAX as i128) as i32
+    }
+
+    /// Returns the counters the driver has accumulated since it was created (or
+    /// since the last call to [`Self::reset_metrics`])
+    pub fn metrics(&self) -> Metrics {
+        self.metrics
+    }
+
+    /// Clears all of the counters returned by [`Self::metrics`]
+    pub fn reset_metrics(&mut self) {
+        self.metrics = Metrics::default();
+    }
+
+    /// Returns the rate the chip has actually been converting at in
+    /// thousandths of a sample per second (e.g. 10_000 at 10 SPS), averaged
+    /// over up to the last 8 conversions that were read back to back
+    ///
+    /// A conversion counts as read back to back if the read started before it
+    /// was ready, so this is only available while reading continuously and
+    /// with a [`Clock`]. Comparing it against [`Speed::conversion_period_us`]
+    /// of [`Config::speed`] catches a SPEED pin strapped differently than
+    /// configured or a chip clock that is off. Returns `None` until two such
+    /// conversions have been read in a row.
+    pub fn measured_sample_rate(&self) -> Option<u32> {
+        self.rate.rate_millihertz()
+    }
+
+    /// Returns the probable fault if at least the last [`WIRING_FAULT_FRAMES`]
+    /// frames were all zeros and DOUT never went high while they were read
+    ///
+    /// This covers every frame the driver shifts out, including the ones
+    /// thrown away while an input settles.
+    pub fn wiring_fault(&self) -> Option<WiringFault> {
+        match self.degenerate {
+            Some((fault, run)) if run >= WIRING_FAULT_FRAMES => Some(fault),
+            _ => None,
+        }
+    }
+
+    /// Returns the power state the driver last put the chip into
+    pub fn power_state(&self) -> PowerState {
+        self.power_state
+    }
+
+    /// Samples the pins and returns them alongside what the driver believes the
+    /// state of the chip to be
+    pub fn debug_snapshot(&mut self) -> DebugSnapshot {
+        let (sclk_high, pwdn_high) = match self.power_state {
+            PowerState::Active => (false, true),
+            PowerState::Standby => (true, true),
+            PowerState::PoweredDown => (false, false),
+        };
+
+        DebugSnapshot {
+            sclk_high,
+            pwdn_high,
+            a0_high: self.a0.is_set_high().unwrap(),
+            a1_high: self.a1.is_set_high().unwrap(),
+            dout_high: self.dout.is_high().unwrap(),
+            channel: self
+                .selected_channel()
+                .map(|channel| M::channel_index(channel) as u8),
+            power_state: self.power_state,
+            frame_unterminated: self.frame_unterminated,
+        }
+    }
+
+    /// Returns the time spent in each power state since the driver was created
+    /// (or since the last call to [`Self::reset_power_profile`]), including
+    /// the time spent in the current state so far
+    pub fn power_profile(&mut self) -> PowerProfile {
+        self.update_power_profile();
+        self.power_profile
+    }
+
+    /// Clears the times returned by [`Self::power_profile`]
+    pub fn reset_power_profile(&mut self) {
+        self.power_profile = PowerProfile::default();
+        self.power_state_since_us = self.clock.now_us();
+    }
+
+    /// Adds the time spent in the current power state since the last update to
+    /// the profile
+    fn update_power_profile(&mut self) {
+        let now = self.clock.now_us();
+        let elapsed = now.saturating_sub(self.power_state_since_us);
+
+        let total = match self.power_state {
+            PowerState::Active => &mut self.power_profile.active_us,
+            PowerState::Standby => &mut self.power_profile.standby_us,
+            PowerState::PoweredDown => &mut self.power_profile.powered_down_us,
+        };
+        *total = total.wrapping_add(elapsed);
+
+        self.power_state_since_us = now;
+    }
+
+    fn set_power_state(&mut self, state: PowerState) {
+        // Standby and power-down restart the conversion cycle, so there is no
+        // frame left to finish off afterwards
+        if state != PowerState::Active {
+            self.frame_unterminated = false;
+            self.rate.clear();
+            self.drdy_anchor_us = None;
+        }
+
+        if state != self.power_state {
+            self.update_power_profile();
+            self.power_state = state;
+        }
+    }
+
+    /// Records that setting SCLK low at the start of a read has woken the chip
+    /// from standby
+    fn exit_standby_state(&mut self) {
+        if self.power_state == PowerState::Standby {
+            self.set_power_state(PowerState::Active);
+        }
+    }
+
+    /// Extends or ends the run of consecutive all-zero frames during which
+    /// DOUT was never seen high
+    fn track_degenerate(&mut self, data: u32, dout_went_high: bool) {
+        let fault = if data == 0 && !dout_went_high {
+            Some(WiringFault::StuckLow)
+        } else {
+            None
+        };
+
+        self.degenerate = match (fault, self.degenerate) {
+            (Some(fault), Some((previous, run))) if fault == previous => {
+                Some((fault, run.saturating_add(1)))
+            }
+            (Some(fault), _) => Some((fault, 1)),
+            (None, _) => None,
+        };
+
+        if let Some((_, WIRING_FAULT_FRAMES)) = self.degenerate {
+            self.metrics.wiring_faults = self.metrics.wiring_faults.wrapping_add(1);
+
+            #[cfg(feature = "trace")]
+            defmt::trace!(
+                "ads123x: {=u32} consecutive degenerate frames",
+                WIRING_FAULT_FRAMES
+            );
+        }
+    }
+
+    /// Rejects the frame just read with [`Error::WiringFault`] if it extended
+    /// a run of degenerate frames to at least [`WIRING_FAULT_FRAMES`]
+    fn check_wiring(&mut self) -> Result<(), Error> {
+        if self.wiring_fault().is_none() {
+            return Ok(());
+        }
+
+        self.hooks.on_error(Error::WiringFault);
+        Err(Error::WiringFault)
+    }
+
+    /// Accepts or rejects a frame ending in a one depending on whether the chip
+    /// was `alive` enough to produce the next conversion
+    fn confirm_frame(&mut self, alive: bool) -> Result<(), Error> {
+        // Either the next conversion replaced the frame or the chip restarted,
+        // so there is nothing left to finish off
+        self.frame_unterminated = false;
+
+        if alive {
+            return Ok(());
+        }
+
+        self.metrics.aborted_frames = self.metrics.aborted_frames.wrapping_add(1);
+
+        #[cfg(feature = "trace")]
+        defmt::trace!("ads123x: frame was aborted");
+
+        self.hooks.on_error(Error::AbortedFrame);
+        Err(Error::AbortedFrame)
+    }
+
+    /// Counts a conversion and how long it took DRDY to go low for it since
+    /// SCLK was set low at `start_us`
+    ///
+    /// `pending` is whether DRDY was still high when the wait started, in
+    /// which case it just went low and the time is that of the conversion.
+    fn record_drdy_latency(&mut self, start_us: u64, pending: bool) {
+        let now_us = self.clock.now_us();
+        let latency_us = now_us.saturating_sub(start_us);
+
+        // A conversion that was already waiting may have been ready for any
+        // number of periods, which breaks the chain of back to back ones
+        if pending {
+            self.rate.push(now_us);
+        } else {
+            self.rate.clear();
+        }
+
+        self.track_overruns(now_us, pending);
+        self.check_read_latency(now_us);
+
+        self.metrics.conversions = self.metrics.conversions.wrapping_add(1);
+        self.metrics.max_drdy_latency_us = self.metrics.max_drdy_latency_us.max(latency_us);
+        self.metrics.total_drdy_wait_us = self.metrics.total_drdy_wait_us.wrapping_add(latency_us);
+    }
+
+    /// Counts the conversions that completed since the previous one that was
+    /// read, other than the one about to be shifted out, as overruns
+    fn track_overruns(&mut self, now_us: u64, pending: bool) {
+        let period_us = self.config.speed.conversion_period_us() as u64;
+
+        let Some(anchor_us) = self.drdy_anchor_us else {
+            self.drdy_anchor_us = Some(now_us);
+            return;
+        };
+
+        let elapsed_us = now_us.saturating_sub(anchor_us);
+
+        let periods = if pending {
+            // DRDY only just went low, which pins down the conversion cycle
+            self.drdy_anchor_us = Some(now_us);
+            (elapsed_us + period_us / 2) / period_us
+        } else {
+            // The waiting conversion completed some time within the last
+            // period, on the cycle of the previous one
+            let periods = (elapsed_us / period_us).max(1);
+            self.drdy_anchor_us = Some((anchor_us + periods * period_us).min(now_us));
+            periods
+        };
+
+        let missed = periods.saturating_sub(1).min(u32::MAX as u64) as u32;
+
+        if missed > 0 {
+            self.metrics.overruns = self.metrics.overruns.wrapping_add(missed);
+            self.hooks.on_overrun(missed);
+
+            #[cfg(feature = "trace")]
+            defmt::trace!("ads123x: missed {=u32} conversions", missed);
+        }
+    }
+
+    /// Flags the conversion about to be shifted out if it has been waiting for
+    /// longer than the limit set with [`Self::set_max_read_latency_us`]
+    fn check_read_latency(&mut self, now_us: u64) {
+        let (Some(max_us), Some(drdy_us)) = (self.max_read_latency_us, self.drdy_anchor_us) else {
+            return;
+        };
+
+        let latency_us = now_us.saturating_sub(drdy_us);
+
+        if latency_us > max_us as u64 {
+            self.metrics.read_latency_violations =
+                self.metrics.read_latency_violations.wrapping_add(1);
+            self.hooks.on_read_latency_exceeded(latency_us);
+
+            #[cfg(feature = "trace")]
+            defmt::trace!(
+                "ads123x: read a conversion {=u64} us after DRDY",
+                latency_us
+            );
+        }
+    }
+
+    /// Creates the state machine for a frame with `pulses` SCLK pulses in
+    /// total, using the timing of the driver
+    ///
+    /// If the previous frame wasn't followed by an extra pulse, this one starts
+    /// by finishing it off as configured by [`Config::trailing_pulse`].
+    fn frame_reader(&mut self, pulses: u32) -> FrameReader {
+        let reader = FrameReader::new(M::BITS, pulses, self.sclk_high_ns(), self.sclk_low_ns());
+        let unterminated = core::mem::replace(&mut self.frame_unterminated, pulses == M::BITS);
+
+        match self.config.trailing_pulse {
+            _ if !unterminated => reader,
+            TrailingPulse::Never => reader.with_wait_for_update(),
+            TrailingPulse::Always | TrailingPulse::Deferred => reader.with_leading_pulse(),
+        }
+    }
+
+    /// Number of SCLK pulses in a regular read
+    fn read_pulses(&self) -> u32 {
+        match self.config.trailing_pulse {
+            TrailingPulse::Always => M::BITS + 1,
+            TrailingPulse::Deferred | TrailingPulse::Never => M::BITS,
+        }
+    }
+
+    /// Bookkeeping once SCLK has been set low to wait for a conversion
+    fn start_conversion(&mut self) {
+        self.exit_standby_state();
+        self.hooks.on_conversion_started();
+    }
+
+    /// Returns the timestamp of a sampled bit if anyone is interested in it
+    fn bit_timestamp(&mut self, on_bit: &Option<&mut dyn FnMut(u32, PinState, u64)>) -> u64 {
+        match on_bit {
+            Some(_) => self.clock.now_us(),
+            None => 0,
+        }
+    }
+
+    /// Checks that the `pulses` SCLK pulses after DRDY went low at `drdy_us`
+    /// were no faster than the datasheet minimums allow, and finished before
+    /// the next conversion could overwrite the data
+    ///
+    /// Durations are only known to the microsecond, so the minimum gets a
+    /// microsecond of slack. A clock that reads zero is taken to be
+    /// [`NoClock`] and skips the check, while one that stepped back can't
+    /// vouch for the timing and counts as a violation.
+    #[cfg(feature = "timing-check")]
+    fn check_frame_timing(&mut self, drdy_us: u64, pulses: u32) {
+        let now_us = self.clock.now_us();
+        if now_us == 0 {
+            return;
+        }
+
+        let Some(elapsed_us) = now_us.checked_sub(drdy_us) else {
+            self.clock_stepped_back();
+            return;
+        };
+        let min_ns = pulses as u64 * 2 * SCLK_MIN_PHASE_NS;
+        let max_us = self.config.speed.conversion_period_us() as u64;
+
+        if (elapsed_us + 1).saturating_mul(1_000) < min_ns || elapsed_us >= max_us {
+            self.metrics.timing_violations = self.metrics.timing_violations.wrapping_add(1);
+
+            #[cfg(feature = "defmt")]
+            defmt::warn!(
+                "ads123x: {=u32} SCLK pulses took {=u64} us, outside {=u64} ns to {=u64} us",
+                pulses,
+                elapsed_us,
+                min_ns,
+                max_us
+            );
+        }
+    }
+
+    /// Checks that the delay after a channel switch started at `start_us`
+    /// lasted at least [`DRDY_SETUP_US`]
+    #[cfg(feature = "timing-check")]
+    fn check_drdy_setup(&mut self, start_us: u64) {
+        let now_us = self.clock.now_us();
+        if now_us == 0 {
+            return;
+        }
+
+        let Some(elapsed_us) = now_us.checked_sub(start_us) else {
+            self.clock_stepped_back();
+            return;
+        };
+
+        if elapsed_us + 1 < DRDY_SETUP_US as u64 {
+            self.metrics.timing_violations = self.metrics.timing_violations.wrapping_add(1);
+
+            #[cfg(feature = "defmt")]
+            defmt::warn!(
+                "ads123x: DRDY setup after a channel switch took {=u64} us, below {=u32} us",
+                elapsed_us,
+                DRDY_SETUP_US
+            );
+        }
+    }
+
+    /// Counts a timing check that couldn't be performed because the clock
+    /// went backwards
+    #[cfg(feature = "timing-check")]
+    fn clock_stepped_back(&mut self) {
+        self.metrics.timing_violations = self.metrics.timing_violations.wrapping_add(1);
+
+        #[cfg(feature = "defmt")]
+        defmt::warn!("ads123x: clock stepped back during a timing check");
+    }
+
+    /// Bookkeeping once a frame that was started at `start_us` has been
+    /// shifted out, `dout_went_high` being whether DOUT was seen high outside
+    /// of the data bits while doing so
+    fn finish_conversion(&mut self, data: u32, start_us: u64, dout_went_high: bool) {
+        self.metrics.last_conversion_us = self.clock.now_us().saturating_sub(start_us);
+        self.track_degenerate(data, dout_went_high);
+        self.hooks
+            .on_conversion_completed(sign_extend(data, M::BITS));
+        self.hooks.on_progress();
+
+        #[cfg(feature = "trace")]
+        defmt::trace!(
+            "ads123x: read frame {=u32:#x} in {=u64} us",
+            data,
+            self.metrics.last_conversion_us
+        );
+    }
+
+    /// Sets PWDN low to power the chip down until the next reset (see
+    /// [`Self::reset_blocking`]), which draws less current than standby mode
+    /// but loses the offset calibration
+    pub fn power_down(&mut self) {
+        self.pwdn.set_low().unwrap();
+        self.set_power_state(PowerState::PoweredDown);
+    }
+
+    /// Returns the time a single SCLK write takes in nanoseconds, as measured
+    /// by [`Self::calibrate_timing_blocking`], which is subtracted from the
+    /// delays of the [`TimingProfile`]
+    pub fn sclk_write_ns(&self) -> u32 {
+        self.sclk_write_ns
+    }
+
+    /// Sets the time a single SCLK write takes in nanoseconds, e.g. to restore
+    /// a previous measurement without repeating it
+    pub fn set_sclk_write_ns(&mut self, ns: u32) {
+        self.sclk_write_ns = ns;
+    }
+
+    /// Returns the longest time a conversion may wait between DRDY going low
+    /// and the driver starting to read it, if limited
+    pub fn max_read_latency_us(&self) -> Option<u32> {
+        self.max_read_latency_us
+    }
+
+    /// Limits the time a conversion may wait between DRDY going low and the
+    /// driver starting to read it, or removes the limit with `None`
+    ///
+    /// Every read that starts later is counted in
+    /// [`Metrics::read_latency_violations`] and reported to
+    /// [`AdsHooks::on_read_latency_exceeded`], which tells a control loop that
+    /// its sample is older than its timing budget allows. A read that had to
+    /// wait for DRDY is never late. For one that found a conversion already
+    /// waiting, the time DRDY went low is estimated from the driver's
+    /// [`Clock`] like [`Metrics::overruns`], so nothing is flagged with
+    /// [`NoClock`] or for the first read after a channel switch, calibration,
+    /// or standby.
+    pub fn set_max_read_latency_us(&mut self, max_us: Option<u32>) {
+        self.max_read_latency_us = max_us;
+    }
+
+    /// Returns the longest time a conversion may wait between DRDY going low
+    /// and the driver starting to read it, if limited
+    #[cfg(feature = "fugit")]
+    pub fn max_read_latency(&self) -> Option<fugit::MicrosDurationU32> {
+        self.max_read_latency_us
+            .map(fugit::MicrosDurationU32::from_ticks)
+    }
+
+    /// Limits the time a conversion may wait between DRDY going low and the
+    /// driver starting to read it, see [`Self::set_max_read_latency_us`]
+    #[cfg(feature = "fugit")]
+    pub fn set_max_read_latency(&mut self, max: Option<fugit::MicrosDurationU32>) {
+        self.set_max_read_latency_us(max.map(|max| max.ticks()));
+    }
+
+    /// Time SCLK is held high for each bit, less the time the writes take
+    fn sclk_high_ns(&self) -> u32 {
+        T::SCLK_HIGH_NS.saturating_sub(self.sclk_write_ns)
+    }
+
+    /// Time SCLK is held low between bits, less the time the writes take
+    fn sclk_low_ns(&self) -> u32 {
+        T::SCLK_LOW_NS.saturating_sub(self.sclk_write_ns)
+    }
+
+    /// Toggles SCLK with the chip powered down (so that it ignores the
+    /// pulses) and records how long each write took
+    fn measure_sclk_write(&mut self) {
+        self.pwdn.set_low().unwrap();
+        self.set_power_state(PowerState::PoweredDown);
+
+        let start = self.clock.now_us();
+
+        for _ in 0..TIMING_CALIBRATION_PULSES {
+            self.sclk.set_high().unwrap();
+            self.sclk.set_low().unwrap();
+        }
+
+        let elapsed_ns = self
+            .clock
+            .now_us()
+            .saturating_sub(start)
+            .saturating_mul(1_000);
+        self.sclk_write_ns =
+            (elapsed_ns / (2 * TIMING_CALIBRATION_PULSES)).min(u32::MAX as u64) as u32;
+
+        #[cfg(feature = "trace")]
+        defmt::trace!("ads123x: SCLK writes take {=u32} ns", self.sclk_write_ns);
+    }
+
+    /// Returns the software offset that is subtracted from every read of the
+    /// given channel
+    pub fn software_offset(&self, channel: M::Channel) -> i32 {
+        self.offsets[M::channel_index(channel)]
+    }
+
+    /// Sets the software offset that is subtracted from every read of the
+    /// given channel, e.g. to restore one that was previously measured with
+    /// [`Self::measure_software_offset_blocking`] and persisted
+    pub fn set_software_offset(&mut self, channel: M::Channel, offset: i32) {
+        self.offsets[M::channel_index(channel)] = offset;
+    }
+
+    /// Returns the system gain correction factor (Q8.24 fixed point, see
+    /// [`UNITY_GAIN`]) that every offset corrected read of the given channel is
+    /// multiplied by
+    pub fn system_gain(&self, channel: M::Channel) -> i32 {
+        self.gains[M::channel_index(channel)]
+    }
+
+    /// Sets the system gain correction factor (Q8.24 fixed point, see
+    /// [`UNITY_GAIN`]) for the given channel, e.g. to restore one that was
+    /// previously measured with [`Self::calibrate_system_gain_blocking`] and
+    /// persisted
+    pub fn set_system_gain(&mut self, channel: M::Channel, gain: i32) {
+        self.gains[M::channel_index(channel)] = gain;
+    }
+
+    /// Returns the linearity correction coefficient of the given channel
+    ///
+    /// See [`Self::set_linearity_correction`] for how it is applied.
+    pub fn linearity_correction(&self, channel: M::Channel) -> i32 {
+        self.linearity[M::channel_index(channel)]
+    }
+
+    /// Sets the linearity correction coefficient of the given channel
+    ///
+    /// After the offset and gain corrections have been applied, a reading `x`
+    /// is corrected to `x + k * x^2` where `k` is this coefficient divided by
+    /// 2^[`LINEARITY_FRACTIONAL_BITS`]. This compensates for the bow-shaped
+    /// non-linearity typical of load cells and other bridge sensors. Defaults
+    /// to 0 (no correction).
+    pub fn set_linearity_correction(&mut self, channel: M::Channel, coefficient: i32) {
+        self.linearity[M::channel_index(channel)] = coefficient;
+    }
+
+    /// Applies the channel's software offset, system gain, and linearity
+    /// corrections to a sign extended reading
+    fn correct(&self, channel: M::Channel, value: i32) -> i32 {
+        self.correct_wide(channel, value)
+            .clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+
+    /// Same as [`Self::correct`], except that the result isn't clamped to the
+    /// range of an i32
+    fn correct_wide(&self, channel: M::Channel, value: i32) -> i64 {
+        let value = value as i64 - self.software_offset(channel) as i64;
+        let value = value.saturating_mul(self.system_gain(channel) as i64) >> GAIN_FRACTIONAL_BITS;
+
+        let square = (value as i128) * (value as i128);
+        let bow =
+            (square * self.linearity_correction(channel) as i128) >> LINEARITY_FRACTIONAL_BITS;
+
+        units::saturate(value as i128 + bow)
+    }
+
+    /// Computes the gain correction factor that maps an averaged (offset
+    /// corrected) reading onto the expected count, if it is representable
+    fn gain_for(measured: i64, expected: i32) -> Option<i32> {
+        if measured == 0 {
+            return None;
+        }
+
+        i32::try_from(((expected as i64) << GAIN_FRACTIONAL_BITS) / measured).ok()
+    }
+
+    /// Sets PWDN low, waits for the AVDD voltage to stabilize, then pulses PWDN
+    /// once before setting it high
+    pub fn reset_blocking(&mut self, delay: &mut impl DelayNs) {
+        #[cfg(feature = "trace")]
+        let start = self.clock.now_us();
+
+        self.pwdn.set_low().unwrap();
+        self.set_power_state(PowerState::PoweredDown);
+
+        // Wait for AVDD to stabilize (we can't easily measure this so we just
+        // wait for a predefined amount of time that should be fine)
+        delay.delay_us(50);
 
         self.pwdn.set_high().unwrap();
         delay.delay_us(26);
@@ -70,178 +1922,1517 @@ where
         delay.delay_us(26);
 
         self.pwdn.set_high().unwrap();
+        self.set_power_state(PowerState::Active);
+
+        #[cfg(feature = "trace")]
+        defmt::trace!(
+            "ads123x: reset in {=u64} us",
+            self.clock.now_us().saturating_sub(start)
+        );
     }
 
-    /// Sets SCLK low, waits for DRDY to go low (blocking), and then pulses the
-    /// SCLK 26 times to initiate calibration offset mode
-    pub fn calibrate_offset_blocking(&mut self, delay: &mut impl DelayNs) {
-        let _ = self.read_internal_blocking(delay);
+    /// Measures how long a write to SCLK takes on this platform and shortens
+    /// the delays of the [`TimingProfile`] by that much, so that reads run as
+    /// fast as the datasheet minimums allow without manual tuning
+    ///
+    /// The chip is powered down while SCLK is toggled and reset afterwards,
+    /// so this is best called once at startup in place of
+    /// [`Self::reset_blocking`]. The measurement relies on the driver's
+    /// [`Clock`], so with [`NoClock`] the delays are left unchanged. Returns
+    /// the measured time per write in nanoseconds.
+    pub fn calibrate_timing_blocking(&mut self, delay: &mut impl DelayNs) -> u32 {
+        self.measure_sclk_write();
+        self.reset_blocking(delay);
+
+        self.sclk_write_ns
+    }
 
-        // Pulse SCLK a 26th time to start calibration
-        self.sclk.set_high().unwrap();
-        delay.delay_ns(100);
-        self.sclk.set_low().unwrap();
+    /// Performs the same power-up sequence as [`Self::reset_blocking`] on all
+    /// of the given chips at once so that their conversion phases line up,
+    /// which matters when readings from several chips should refer to the same
+    /// instant
+    ///
+    /// Each step of the sequence writes the PWDN pins one after another, so the
+    /// chips are released within a few GPIO writes of each other. Unless the
+    /// chips share an external clock on CLKIN their phases will slowly drift
+    /// apart again, so this should be repeated periodically if the alignment
+    /// needs to be maintained.
+    pub fn sync_start_blocking(chips: &mut [&mut Self], delay: &mut impl DelayNs) {
+        Self::set_pwdn_all(chips, PinState::Low);
+
+        // Wait for AVDD to stabilize (same as for a single chip reset)
+        delay.delay_us(50);
+
+        Self::set_pwdn_all(chips, PinState::High);
+        delay.delay_us(26);
+
+        Self::set_pwdn_all(chips, PinState::Low);
+        delay.delay_us(26);
+
+        Self::set_pwdn_all(chips, PinState::High);
+
+        #[cfg(feature = "trace")]
+        defmt::trace!("ads123x: synchronously started {=usize} chips", chips.len());
+    }
+
+    fn set_pwdn_all(chips: &mut [&mut Self], state: PinState) {
+        for chip in chips.iter_mut() {
+            chip.pwdn.set_state(state).unwrap();
+            chip.set_power_state(match state {
+                PinState::Low => PowerState::PoweredDown,
+                PinState::High => PowerState::Active,
+            });
+        }
+    }
+
+    /// Sets SCLK low, waits for DRDY to go low (blocking), and then pulses the
+    /// SCLK two more times than the data width (26 times on 24-bit models) to
+    /// initiate calibration offset mode
+    ///
+    /// Returns how long the calibration took in microseconds as measured by
+    /// the driver's [`Clock`] (always zero with [`NoClock`]), from setting
+    /// SCLK low until DRDY signalled completion, which is also kept in
+    /// [`Metrics::last_calibration_us`]. The wait for completion is
+    /// bounded by [`Speed::calibration_timeout_us`] for the SPEED in the
+    /// [`Config`], and [`Error::CalibrationTimeout`] is returned if DRDY
+    /// doesn't go low in time.
+    pub fn calibrate_offset_blocking(&mut self, delay: &mut impl DelayNs) -> Result<u64, Error> {
+        let start = self.clock.now_us();
+
+        // Pulse SCLK an extra time after the frame (the 26th on 24-bit models)
+        // to start calibration
+        let _ = self.shift_frame_blocking(delay, M::BITS + 2, None);
 
         // Wait for DRDY to go low again which signals that calibration is
         // complete
-        while self.dout.is_high().unwrap() {}
+        let timeout_us = self.config.speed.calibration_timeout_us();
+        let completed = self.wait_for_drdy_timeout_blocking(delay, Some(timeout_us));
+        self.drdy_anchor_us = None;
+
+        self.finish_offset_calibration(start, completed)
+    }
+
+    /// Records the outcome of an offset calibration started at `start` (in
+    /// microseconds) and returns how long it took
+    fn finish_offset_calibration(&mut self, start: u64, completed: bool) -> Result<u64, Error> {
+        let duration_us = self.clock.now_us().saturating_sub(start);
+
+        if !completed {
+            self.metrics.calibration_timeouts = self.metrics.calibration_timeouts.wrapping_add(1);
+
+            #[cfg(feature = "trace")]
+            defmt::trace!(
+                "ads123x: timed out calibrating offset after {=u64} us",
+                duration_us
+            );
+
+            self.hooks.on_error(Error::CalibrationTimeout);
+            return Err(Error::CalibrationTimeout);
+        }
+
+        self.metrics.calibrations = self.metrics.calibrations.wrapping_add(1);
+        self.metrics.last_calibration_us = duration_us;
+        self.hooks.on_calibration(CalibrationKind::Offset);
+        self.hooks.on_progress();
+
+        #[cfg(feature = "trace")]
+        defmt::trace!("ads123x: calibrated offset in {=u64} us", duration_us);
+
+        Ok(duration_us)
+    }
+
+    /// Sets SCLK low, waits for DRDY to go low (blocking), and then sets SCLK
+    /// high to initiate standby mode (will take 12ms when SPEED is high and
+    /// 99ms when speed is low to actually initiate standby)
+    pub fn enter_standby_blocking(&mut self, delay: &mut impl DelayNs) {
+        #[cfg(feature = "trace")]
+        let start = self.clock.now_us();
+
+        self.sclk.set_low().unwrap();
+
+        self.wait_for_drdy_blocking(delay);
+
+        self.sclk.set_high().unwrap();
+        self.set_power_state(PowerState::Standby);
+        self.hooks.on_progress();
+
+        #[cfg(feature = "trace")]
+        defmt::trace!(
+            "ads123x: entered standby after {=u64} us",
+            self.clock.now_us().saturating_sub(start)
+        );
+    }
+
+    /// Sets SCLK low to exit standby mode (or after [`Self::reset_blocking`]),
+    /// and waits for the first conversion to be ready
+    ///
+    /// The wait is bounded by [`Speed::wake_timeout_us`] for the SPEED in the
+    /// [`Config`], and [`Error::WakeTimeout`] is returned if DRDY doesn't go
+    /// low in time, so that a chip that failed to wake can be told apart from
+    /// one that is merely slow. The conversion itself is left to be read by
+    /// the next regular read.
+    pub fn wake_blocking(&mut self, delay: &mut impl DelayNs) -> Result<(), Error> {
+        #[cfg(feature = "trace")]
+        let start = self.clock.now_us();
+
+        self.sclk.set_low().unwrap();
+        self.set_power_state(PowerState::Active);
+
+        let timeout_us = self.config.speed.wake_timeout_us();
+
+        if !self.wait_for_drdy_timeout_blocking(delay, Some(timeout_us)) {
+            self.metrics.wake_timeouts = self.metrics.wake_timeouts.wrapping_add(1);
+
+            #[cfg(feature = "trace")]
+            defmt::trace!("ads123x: timed out waking up after {=u32} us", timeout_us);
+
+            self.hooks.on_error(Error::WakeTimeout);
+            return Err(Error::WakeTimeout);
+        }
+
+        #[cfg(feature = "trace")]
+        defmt::trace!(
+            "ads123x: woke up in {=u64} us",
+            self.clock.now_us().saturating_sub(start)
+        );
+
+        Ok(())
+    }
+
+    /// Reads the next raw frame from the currently selected channel, invoking
+    /// `on_bit` with the index (0 being the MSB), level, and clock timestamp of
+    /// each data bit as it is sampled
+    ///
+    /// This is intended for chasing signal-integrity issues, where the
+    /// firmware's view of each bit needs to be correlated with a scope capture.
+    /// Unlike the regular reads it doesn't switch channels or throw away any
+    /// settling conversions.
+    pub fn read_raw_debug_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        mut on_bit: impl FnMut(u32, PinState, u64),
+    ) -> u32 {
+        self.read_internal_blocking(delay, Some(&mut on_bit))
+    }
+
+    /// Returns the channel currently selected by the mux pins, or `None` if
+    /// they are in an invalid state
+    fn selected_channel(&mut self) -> Option<M::Channel> {
+        M::decode_mux(
+            self.a0.is_set_high().unwrap(),
+            self.a1.is_set_high().unwrap(),
+        )
+    }
+
+    /// Sets the mux to the given channel if it isn't already selected and
+    /// returns the previously selected channel, or `None` if the mux pins were
+    /// in an invalid state
+    #[must_use]
+    fn set_channel(&mut self, channel: M::Channel) -> Option<M::Channel> {
+        let old_channel = self.selected_channel();
+
+        if old_channel.is_none() {
+            self.metrics.invalid_mux_states = self.metrics.invalid_mux_states.wrapping_add(1);
+
+            #[cfg(feature = "trace")]
+            defmt::trace!("ads123x: mux pins were in an invalid state");
+        }
+
+        if old_channel != Some(channel) {
+            let (a0, a1) = M::mux_state(channel);
+
+            self.a0.set_state(a0).unwrap();
+            self.a1.set_state(a1).unwrap();
+
+            // Switching channels restarts the conversion cycle
+            self.rate.clear();
+            self.drdy_anchor_us = None;
+
+            #[cfg(feature = "trace")]
+            defmt::trace!(
+                "ads123x: changed channel from {} to {}",
+                defmt::Debug2Format(&old_channel),
+                defmt::Debug2Format(&channel)
+            );
+        }
+
+        old_channel
+    }
+
+    /// Averages `n` reads (at least one) from the given channel and stores the
+    /// result as its software offset, which is then subtracted from every
+    /// subsequent read of that channel
+    ///
+    /// The channel's input should be shorted, or otherwise held at its zero
+    /// point, while this runs. Unlike the chip's internal offset calibration
+    /// this also cancels out offsets introduced by the sensor and wiring. The
+    /// measured offset is returned so that it can be persisted and later
+    /// restored with [`Self::set_software_offset`].
+    pub fn measure_software_offset_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channel: M::Channel,
+        n: u32,
+    ) -> i32 {
+        let offset = self.average_blocking(delay, channel, n) as i32;
+        self.set_software_offset(channel, offset);
+        self.hooks.on_calibration(CalibrationKind::SoftwareOffset);
+
+        offset
+    }
+
+    /// Averages `n` reads (at least one) from the given channel while a known
+    /// reference input is applied and stores the gain correction factor that
+    /// makes the offset corrected reading equal `expected` counts
+    ///
+    /// The factor is applied to every subsequent read of that channel and is
+    /// returned (as Q8.24 fixed point, see [`UNITY_GAIN`]) so that it can be
+    /// persisted and later restored with [`Self::set_system_gain`]. The
+    /// software offset should be measured first so that the combination of the
+    /// two performs a full system calibration.
+    ///
+    /// If the reference reads as zero, or the required factor is out of range,
+    /// the stored factor is left unchanged and `None` is returned.
+    pub fn calibrate_system_gain_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channel: M::Channel,
+        expected: i32,
+        n: u32,
+    ) -> Option<i32> {
+        let measured =
+            self.average_blocking(delay, channel, n) - self.software_offset(channel) as i64;
+        let gain = Self::gain_for(measured, expected)?;
+        self.set_system_gain(channel, gain);
+        self.hooks.on_calibration(CalibrationKind::SystemGain);
+
+        Some(gain)
+    }
+
+    /// Reads from the given channel and returns the input-referred voltage
+    /// after applying the channel's corrections
+    ///
+    /// See [`Self::counts_to_voltage`] for how the voltage is computed.
+    pub fn read_volts_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channel: M::Channel,
+    ) -> Voltage {
+        let counts = self.read_calibrated_blocking(delay, channel).corrected;
+
+        self.counts_to_voltage(counts)
+    }
+
+    /// Averages `N` consecutive corrected reads (at least one) from the given
+    /// channel and returns the average with extra fractional bits of
+    /// resolution, see [`OversampledReading`]
+    ///
+    /// This takes `N` conversion periods, e.g. 6.4 s for 64 conversions at
+    /// 10 SPS, on top of any settling conversions after a channel change.
+    pub fn read_oversampled_blocking<const N: usize>(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channel: M::Channel,
+    ) -> OversampledReading {
+        let mut sum = 0i64;
+
+        for _ in 0..N.max(1) {
+            sum += self.read_calibrated_blocking(delay, channel).corrected as i64;
+        }
+
+        OversampledReading::from_sum(sum, N)
+    }
+
+    /// Averages `n` corrected reads (at least one) from the given channel
+    ///
+    /// The reads are accumulated in wide integers, so `n` can span minutes
+    /// (e.g. 4800 reads for one minute at 80 SPS) without overflowing.
+    ///
+    /// If `max_variance` (in counts squared) is given and the variance of the
+    /// reads exceeds it, the measurement is rejected with [`Error::Unstable`]
+    /// so that control logic never acts on a reading taken while the input
+    /// was moving.
+    pub fn read_averaged_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channel: M::Channel,
+        n: u32,
+        max_variance: Option<u64>,
+    ) -> Result<i32, Error> {
+        let mut accumulator = Accumulator::new();
+
+        for _ in 0..n.max(1) {
+            accumulator.push(self.read_calibrated_blocking(delay, channel).corrected);
+        }
+
+        self.gate(&accumulator, max_variance)
+    }
+
+    /// Returns the mean of the accumulated reads unless their variance exceeds
+    /// the allowed maximum
+    fn gate(&mut self, accumulator: &Accumulator, max_variance: Option<u64>) -> Result<i32, Error> {
+        let variance = accumulator.variance().unwrap_or(0);
+
+        match max_variance {
+            Some(max_variance) if variance > max_variance => {
+                self.hooks.on_error(Error::Unstable);
+                Err(Error::Unstable)
+            }
+            _ => Ok(accumulator.mean().unwrap_or(0)),
+        }
+    }
+
+    /// Averages `n` reads (at least one) from the given channel without
+    /// applying any corrections
+    fn average_blocking(&mut self, delay: &mut impl DelayNs, channel: M::Channel, n: u32) -> i64 {
+        let n = n.max(1);
+        let mut sum = 0i64;
+
+        for _ in 0..n {
+            sum += sign_extend(self.read_channel_blocking(delay, channel), M::BITS) as i64;
+        }
+
+        sum / n as i64
+    }
+
+    /// Switches the mux to the given channel, throws away any conversions
+    /// required for the input to settle, and then reads a raw frame from it
+    fn read_channel_blocking(&mut self, delay: &mut impl DelayNs, channel: M::Channel) -> u32 {
+        self.select_channel_blocking(delay, channel);
+        self.read_internal_blocking(delay, None)
+    }
+
+    /// Switches the mux to the given channel and throws away any conversions
+    /// required for the input to settle, returning whether the channel changed
+    /// and how many conversions were thrown away
+    fn select_channel_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channel: M::Channel,
+    ) -> (bool, u32) {
+        let old_channel = self.set_channel(channel);
+
+        // Wait for DRDY setup time if we changed the channel
+        if old_channel != Some(channel) {
+            #[cfg(feature = "timing-check")]
+            let setup_start = self.clock.now_us();
+
+            delay.delay_us(DRDY_SETUP_US);
+
+            #[cfg(feature = "timing-check")]
+            self.check_drdy_setup(setup_start);
+        }
+
+        let discards = match old_channel {
+            Some(old_channel) => M::settling_discards(old_channel, channel),
+            None => M::MAX_SETTLING_DISCARDS,
+        };
+
+        for _ in 0..discards {
+            self.read_internal_blocking(delay, None);
+        }
+
+        (old_channel != Some(channel), discards)
+    }
+
+    /// Reads from the given channel and returns the conversion result both as
+    /// it was read and with the channel's software offset, system gain, and
+    /// linearity corrections applied, so applications don't have to stitch
+    /// the correction chain together themselves
+    pub fn read_calibrated_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channel: M::Channel,
+    ) -> CalibratedReading {
+        let raw = sign_extend(self.read_channel_blocking(delay, channel), M::BITS);
+
+        CalibratedReading {
+            raw,
+            corrected: self.correct(channel, raw),
+        }
+    }
+
+    /// Same as [`Self::read_calibrated_blocking`], except that frames the chip
+    /// may have stopped shifting out half way are rejected with
+    /// [`Error::AbortedFrame`] instead of being returned as a sample, and
+    /// frames that extend a run of degenerate ones (see
+    /// [`Self::wiring_fault`]) with [`Error::WiringFault`]
+    ///
+    /// A chip that browns out or is powered down leaves DOUT high, so such a
+    /// frame reads as ones from that point on. A frame whose last bit is a one
+    /// is therefore only accepted once DRDY goes low again within one and a
+    /// half conversion periods, which a chip that restarted can't do as it
+    /// takes several periods to wake. As the last bit is a one in about half
+    /// of all conversions, about half of the checked reads wait for up to one
+    /// and a half periods of [`Config::speed`] longer than a regular read (the
+    /// next read then picks up the conversion it waited for without waiting).
+    ///
+    /// Only the checked reads do this. The other reads return whatever was
+    /// shifted out, so a frame cut short by a brown-out reads as a valid
+    /// sample there.
+    pub fn read_checked_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channel: M::Channel,
+    ) -> Result<CalibratedReading, Error> {
+        let data = self.read_channel_blocking(delay, channel);
+        self.check_wiring()?;
+
+        if data & 1 != 0 {
+            let timeout_us = self.config.speed.conversion_period_us() * 3 / 2;
+            let alive = self.wait_for_drdy_timeout_blocking(delay, Some(timeout_us));
+            self.confirm_frame(alive)?;
+        }
+
+        let raw = sign_extend(data, M::BITS);
+
+        Ok(CalibratedReading {
+            raw,
+            corrected: self.correct(channel, raw),
+        })
+    }
+
+    /// Reads from the given channel and returns the conversion result with the
+    /// channel's corrections applied, without clamping it to the range of an
+    /// i32
+    ///
+    /// A system gain above unity or a linearity correction can take a
+    /// full-scale reading past `i32::MAX`, which the other reads clamp. Pair
+    /// this with [`Self::counts_to_voltage_wide`] or
+    /// [`scale::Scale::counts_to_micrograms`] to keep the whole pipeline in
+    /// 64 bits.
+    pub fn read_wide_blocking(&mut self, delay: &mut impl DelayNs, channel: M::Channel) -> i64 {
+        let raw = sign_extend(self.read_channel_blocking(delay, channel), M::BITS);
+
+        self.correct_wide(channel, raw)
+    }
+
+    /// Reads from the given channel like [`Self::read_calibrated_blocking`],
+    /// and also reports whether the chip had to be woken from standby, whether
+    /// the channel was switched, how many conversions were thrown away, and
+    /// how long the read spent waiting for DRDY
+    ///
+    /// This explains where the time of an unexpectedly slow read went, e.g. a
+    /// read taking 500 ms at 10 SPS because switching to the TEMP channel
+    /// threw away 4 conversions.
+    pub fn read_with_status_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channel: M::Channel,
+    ) -> ReadStatus {
+        let start = self.clock.now_us();
+        let exited_standby = self.power_state == PowerState::Standby;
+        let drdy_wait_before = self.metrics.total_drdy_wait_us;
+        let overruns_before = self.metrics.overruns;
+
+        let (channel_changed, discarded) = self.select_channel_blocking(delay, channel);
+        let raw = sign_extend(self.read_internal_blocking(delay, None), M::BITS);
+
+        ReadStatus {
+            reading: CalibratedReading {
+                raw,
+                corrected: self.correct(channel, raw),
+            },
+            exited_standby,
+            channel_changed,
+            discarded,
+            drdy_wait_us: self
+                .metrics
+                .total_drdy_wait_us
+                .wrapping_sub(drdy_wait_before),
+            duration_us: self.clock.now_us().saturating_sub(start),
+            overruns: self.metrics.overruns.wrapping_sub(overruns_before),
+        }
+    }
+
+    /// Reads from the channel set with [`Self::set_default_channel`] and
+    /// returns the corrected value, so single-channel applications don't need
+    /// to pass the channel to every call
+    pub fn read_default_blocking(&mut self, delay: &mut impl DelayNs) -> i32 {
+        let channel = self.default_channel;
+        self.read_calibrated_blocking(delay, channel).corrected
+    }
+
+    /// Reads each of the given channels in order and writes the corrected
+    /// values into `out`, returning the number of values written (the shorter
+    /// of the two lengths)
+    ///
+    /// The mux is switched (with any required settling conversions thrown
+    /// away) only between distinct consecutive channels, so repeating a channel
+    /// reads it back to back. This is handy when only some inputs are
+    /// populated, e.g. `&[AIN1, AIN3]` on an ADS1234.
+    pub fn read_sequence_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channels: &[M::Channel],
+        out: &mut [i32],
+    ) -> usize {
+        let n = channels.len().min(out.len());
+
+        for (&channel, value) in channels.iter().zip(out.iter_mut()) {
+            *value = self.read_calibrated_blocking(delay, channel).corrected;
+        }
+
+        n
+    }
+
+    /// Wakes the chip if it is in standby, reads `n` consecutive conversions
+    /// from the given channel into `out`, and enters standby again, returning
+    /// the number of values written (the smaller of `n` and the length of
+    /// `out`)
+    ///
+    /// This is the usual pattern for battery powered loggers that take a short
+    /// burst of readings at long intervals. Fails with [`Error::WakeTimeout`]
+    /// (without entering standby) if the chip doesn't wake up.
+    pub fn capture_burst_then_standby_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channel: M::Channel,
+        n: usize,
+        out: &mut [i32],
+    ) -> Result<usize, Error> {
+        if self.power_state == PowerState::Standby {
+            self.wake_blocking(delay)?;
+        }
+
+        let n = n.min(out.len());
+
+        for value in out[..n].iter_mut() {
+            *value = self.read_calibrated_blocking(delay, channel).corrected;
+        }
+
+        self.enter_standby_blocking(delay);
+
+        Ok(n)
+    }
+
+    /// Reads consecutive conversions from the given channel into `out` until
+    /// the driver's [`Clock`] reaches `deadline_us` or the buffer is full
+    ///
+    /// The deadline is only checked before each read, so the capture may end
+    /// up to a conversion period after it. With [`NoClock`] the time never
+    /// advances, so the buffer is always filled and no conversions are
+    /// reported as missed.
+    pub fn capture_until_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channel: M::Channel,
+        deadline_us: u64,
+        out: &mut [TimestampedReading],
+    ) -> CaptureSummary {
+        let period_us = self.config.speed.conversion_period_us();
+        let mut summary = CaptureSummary::default();
+        let mut previous_us = None;
+
+        for slot in out.iter_mut() {
+            if self.clock.now_us() >= deadline_us {
+                break;
+            }
+
+            let value = self.read_calibrated_blocking(delay, channel).corrected;
+            let timestamp_us = self.clock.now_us();
+
+            *slot = TimestampedReading {
+                value,
+                timestamp_us,
+            };
+            summary.record(timestamp_us, previous_us, period_us);
+            previous_us = Some(timestamp_us);
+        }
+
+        summary
+    }
+
+    /// Reads consecutive conversions from the given channel into `out` until
+    /// the driver's [`Clock`] reaches `deadline` or the buffer is full, see
+    /// [`Self::capture_until_blocking`]
+    #[cfg(feature = "fugit")]
+    pub fn capture_until_instant_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        channel: M::Channel,
+        deadline: fugit::TimerInstantU64<1_000_000>,
+        out: &mut [TimestampedReading],
+    ) -> CaptureSummary {
+        self.capture_until_blocking(delay, channel, deadline.ticks(), out)
+    }
+
+    /// Polls DOUT until DRDY goes low, calling [`AdsHooks::on_busy_wait`]
+    /// roughly once per millisecond in the meantime
+    fn wait_for_drdy_blocking(&mut self, delay: &mut impl DelayNs) {
+        self.wait_for_drdy_timeout_blocking(delay, None);
+    }
+
+    /// Same as [`Self::wait_for_drdy_blocking`], except that it gives up after
+    /// roughly `timeout_us` microseconds and returns whether DRDY went low
+    fn wait_for_drdy_timeout_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        timeout_us: Option<u32>,
+    ) -> bool {
+        let mut since_hook_us = 0;
+        let mut waited_us = 0u32;
+
+        while self.dout.is_high().unwrap() {
+            if timeout_us.is_some_and(|timeout_us| waited_us >= timeout_us) {
+                return false;
+            }
+
+            delay.delay_us(DRDY_POLL_INTERVAL_US);
+            since_hook_us += DRDY_POLL_INTERVAL_US;
+            waited_us = waited_us.saturating_add(DRDY_POLL_INTERVAL_US);
+
+            if since_hook_us >= BUSY_WAIT_HOOK_INTERVAL_US {
+                self.hooks.on_busy_wait();
+                since_hook_us = 0;
+            }
+        }
+
+        true
+    }
+
+    /// Spins until DOUT goes high for the update that precedes the next
+    /// conversion, returning whether it did
+    ///
+    /// DOUT only goes high briefly, so this polls without any delay and calls
+    /// [`AdsHooks::on_busy_wait`] every [`UPDATE_POLLS_PER_HOOK`] polls. It
+    /// gives up once one and a half conversion periods have passed on the
+    /// [`Clock`], by which time the update has been missed and DRDY is low for
+    /// the next conversion. Without a clock it only returns once the update is
+    /// caught.
+    fn wait_for_update_blocking(&mut self) -> bool {
+        let start = self.clock.now_us();
+        let timeout_us = u64::from(self.config.speed.conversion_period_us() * 3 / 2);
+        let mut polls = 0;
+
+        while self.dout.is_low().unwrap() {
+            polls += 1;
+
+            if polls == UPDATE_POLLS_PER_HOOK {
+                self.hooks.on_busy_wait();
+                polls = 0;
+
+                if self.clock.now_us().saturating_sub(start) >= timeout_us {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Delays for `us` microseconds, calling [`AdsHooks::on_busy_wait`]
+    /// roughly once per millisecond in the meantime
+    pub(crate) fn sleep_blocking(&mut self, delay: &mut impl DelayNs, mut us: u32) {
+        while us > 0 {
+            let step = us.min(BUSY_WAIT_HOOK_INTERVAL_US);
+            delay.delay_us(step);
+            us -= step;
+
+            self.hooks.on_busy_wait();
+        }
+    }
+
+    /// Sets SCLK low, waits for DRDY to go low (blocking), and then pulses the
+    /// SCLK to extract the data from DOUT
+    ///
+    /// This operation automatically exits standby mode and the first available
+    /// data is guaranteed to be valid. The raw frame is returned right aligned
+    /// and without sign extension.
+    fn read_internal_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        on_bit: Option<&mut dyn FnMut(u32, PinState, u64)>,
+    ) -> u32 {
+        let pulses = self.read_pulses();
+        self.shift_frame_blocking(delay, pulses, on_bit)
+    }
+
+    /// Carries out the actions of a [`FrameReader`] that pulses SCLK `pulses`
+    /// times in total
+    fn shift_frame_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        pulses: u32,
+        mut on_bit: Option<&mut dyn FnMut(u32, PinState, u64)>,
+    ) -> u32 {
+        let start = self.clock.now_us();
+        let mut reader = self.frame_reader(pulses);
+        let mut timestamp = 0;
+        let mut dout_went_high = false;
+
+        #[cfg(feature = "timing-check")]
+        let mut drdy_us = start;
+
+        loop {
+            match reader.next_action() {
+                Action::SetSclk(state) => self.sclk.set_state(state).unwrap(),
+                Action::Delay(ns) => delay.delay_ns(ns),
+                Action::WaitForUpdate => dout_went_high |= self.wait_for_update_blocking(),
+                Action::WaitForDrdy => {
+                    self.start_conversion();
+                    let pending = self.dout.is_high().unwrap();
+                    self.wait_for_drdy_blocking(delay);
+                    self.record_drdy_latency(start, pending);
+                    dout_went_high |= pending;
+
+                    #[cfg(feature = "timing-check")]
+                    {
+                        drdy_us = self.clock.now_us();
+                    }
+                }
+                Action::SampleDout => {
+                    reader.sampled(self.dout.is_high().unwrap());
+                    timestamp = self.bit_timestamp(&on_bit);
+                }
+                Action::BitRead { index, level } => {
+                    if let Some(on_bit) = on_bit.as_mut() {
+                        on_bit(index, level, timestamp);
+                    }
+                }
+                Action::Done(data) => {
+                    #[cfg(feature = "timing-check")]
+                    self.check_frame_timing(drdy_us, pulses);
+
+                    // A trailing pulse forces DOUT high on a working chip
+                    if pulses > M::BITS {
+                        dout_went_high |= self.dout.is_high().unwrap();
+                    }
+
+                    self.finish_conversion(data, start, dout_went_high);
+                    return data;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin + embedded_hal_async::digital::Wait,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Sets PWDN low, waits for the AVDD voltage to stabilize, then pulses PWDN
+    /// once before setting it high
+    pub async fn reset(&mut self, delay: &mut impl embedded_hal_async::delay::DelayNs) {
+        #[cfg(feature = "trace")]
+        let start = self.clock.now_us();
+
+        self.pwdn.set_low().unwrap();
+        self.set_power_state(PowerState::PoweredDown);
+
+        // Wait for AVDD to stabilize (we can't easily measure this so we just
+        // wait for a predefined amount of time that should be fine)
+        delay.delay_us(50).await;
+
+        self.pwdn.set_high().unwrap();
+        delay.delay_us(26).await;
+
+        self.pwdn.set_low().unwrap();
+        delay.delay_us(26).await;
+
+        self.pwdn.set_high().unwrap();
+        self.set_power_state(PowerState::Active);
+
+        #[cfg(feature = "trace")]
+        defmt::trace!(
+            "ads123x: reset in {=u64} us",
+            self.clock.now_us().saturating_sub(start)
+        );
+    }
+
+    /// Measures how long a write to SCLK takes on this platform and shortens
+    /// the delays of the [`TimingProfile`] by that much
+    ///
+    /// See [`Self::calibrate_timing_blocking`] for details.
+    pub async fn calibrate_timing(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> u32 {
+        self.measure_sclk_write();
+        self.reset(delay).await;
+
+        self.sclk_write_ns
+    }
+
+    /// Performs the same power-up sequence as [`Self::reset`] on all of the
+    /// given chips at once so that their conversion phases line up, which
+    /// matters when readings from several chips should refer to the same
+    /// instant
+    ///
+    /// See [`Self::sync_start_blocking`] for how closely the chips are aligned.
+    pub async fn sync_start(
+        chips: &mut [&mut Self],
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) {
+        Self::set_pwdn_all(chips, PinState::Low);
+
+        // Wait for AVDD to stabilize (same as for a single chip reset)
+        delay.delay_us(50).await;
+
+        Self::set_pwdn_all(chips, PinState::High);
+        delay.delay_us(26).await;
+
+        Self::set_pwdn_all(chips, PinState::Low);
+        delay.delay_us(26).await;
+
+        Self::set_pwdn_all(chips, PinState::High);
+
+        #[cfg(feature = "trace")]
+        defmt::trace!("ads123x: synchronously started {=usize} chips", chips.len());
+    }
+
+    /// Sets SCLK low, waits for DRDY to go low, and then pulses the SCLK two
+    /// more times than the data width (26 times on 24-bit models) to initiate
+    /// calibration offset mode
+    ///
+    /// See [`Self::calibrate_offset_blocking`] for details.
+    pub async fn calibrate_offset(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> Result<u64, Error> {
+        let start = self.clock.now_us();
+
+        // Pulse SCLK an extra time after the frame (the 26th on 24-bit models)
+        // to start calibration
+        let _ = self.shift_frame(delay, M::BITS + 2, None).await;
+
+        // Wait for DRDY to go low again which signals that calibration is
+        // complete
+        let timeout_us = self.config.speed.calibration_timeout_us();
+        let completed = with_timeout(self.wait_for_drdy_edge(false), delay.delay_us(timeout_us))
+            .await
+            .is_some();
+        self.drdy_anchor_us = None;
+
+        self.finish_offset_calibration(start, completed)
+    }
+
+    /// Sets SCLK low, waits for DRDY to go low, and then sets SCLK high to
+    /// initiate standby mode (will take 12ms when SPEED is high and 99ms when
+    /// speed is low to actually initiate standby)
+    pub async fn enter_standby(&mut self) {
+        #[cfg(feature = "trace")]
+        let start = self.clock.now_us();
+
+        self.sclk.set_low().unwrap();
+        self.wait_for_drdy_edge(false).await;
+        self.sclk.set_high().unwrap();
+        self.set_power_state(PowerState::Standby);
+        self.hooks.on_progress();
+
+        #[cfg(feature = "trace")]
+        defmt::trace!(
+            "ads123x: entered standby after {=u64} us",
+            self.clock.now_us().saturating_sub(start)
+        );
+    }
+
+    /// Sets SCLK low to exit standby mode (or after [`Self::reset`]), and waits
+    /// asynchronously for the first conversion to be ready
+    ///
+    /// See [`Self::wake_blocking`] for details.
+    pub async fn wake(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> Result<(), Error> {
+        #[cfg(feature = "trace")]
+        let start = self.clock.now_us();
+
+        self.sclk.set_low().unwrap();
+        self.set_power_state(PowerState::Active);
+
+        let timeout_us = self.config.speed.wake_timeout_us();
+
+        if with_timeout(self.wait_for_drdy_edge(false), delay.delay_us(timeout_us))
+            .await
+            .is_none()
+        {
+            self.metrics.wake_timeouts = self.metrics.wake_timeouts.wrapping_add(1);
+
+            #[cfg(feature = "trace")]
+            defmt::trace!("ads123x: timed out waking up after {=u32} us", timeout_us);
+
+            self.hooks.on_error(Error::WakeTimeout);
+            return Err(Error::WakeTimeout);
+        }
+
+        #[cfg(feature = "trace")]
+        defmt::trace!(
+            "ads123x: woke up in {=u64} us",
+            self.clock.now_us().saturating_sub(start)
+        );
+
+        Ok(())
+    }
+
+    /// Reads the next raw frame from the currently selected channel
+    /// asynchronously, invoking `on_bit` with the index (0 being the MSB),
+    /// level, and clock timestamp of each data bit as it is sampled
+    ///
+    /// This is intended for chasing signal-integrity issues, where the
+    /// firmware's view of each bit needs to be correlated with a scope capture.
+    /// Unlike the regular reads it doesn't switch channels or throw away any
+    /// settling conversions.
+    pub async fn read_raw_debug(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        mut on_bit: impl FnMut(u32, PinState, u64),
+    ) -> u32 {
+        self.read_internal(delay, Some(&mut on_bit)).await
+    }
+
+    /// Averages `n` reads (at least one) from the given channel asynchronously
+    /// and stores the result as its software offset, which is then subtracted
+    /// from every subsequent read of that channel
+    ///
+    /// See [`Self::measure_software_offset_blocking`] for details.
+    pub async fn measure_software_offset(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+        n: u32,
+    ) -> i32 {
+        let offset = self.average(delay, channel, n).await as i32;
+        self.set_software_offset(channel, offset);
+        self.hooks.on_calibration(CalibrationKind::SoftwareOffset);
+
+        offset
+    }
+
+    /// Averages `n` reads (at least one) from the given channel asynchronously
+    /// while a known reference input is applied and stores the gain correction
+    /// factor that makes the offset corrected reading equal `expected` counts
+    ///
+    /// See [`Self::calibrate_system_gain_blocking`] for details.
+    pub async fn calibrate_system_gain(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+        expected: i32,
+        n: u32,
+    ) -> Option<i32> {
+        let measured = self.average(delay, channel, n).await - self.software_offset(channel) as i64;
+        let gain = Self::gain_for(measured, expected)?;
+        self.set_system_gain(channel, gain);
+        self.hooks.on_calibration(CalibrationKind::SystemGain);
+
+        Some(gain)
+    }
+
+    /// Reads from the given channel asynchronously and returns the
+    /// input-referred voltage after applying the channel's corrections
+    ///
+    /// See [`Self::counts_to_voltage`] for how the voltage is computed.
+    pub async fn read_volts(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+    ) -> Voltage {
+        let counts = self.read_calibrated(delay, channel).await.corrected;
+
+        self.counts_to_voltage(counts)
+    }
+
+    /// Averages `N` consecutive corrected reads (at least one) from the given
+    /// channel asynchronously and returns the average with extra fractional
+    /// bits of resolution, see [`OversampledReading`]
+    pub async fn read_oversampled<const N: usize>(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+    ) -> OversampledReading {
+        let mut sum = 0i64;
+
+        for _ in 0..N.max(1) {
+            sum += self.read_calibrated(delay, channel).await.corrected as i64;
+        }
+
+        OversampledReading::from_sum(sum, N)
+    }
+
+    /// Averages `n` corrected reads (at least one) from the given channel
+    /// asynchronously
+    ///
+    /// See [`Self::read_averaged_blocking`] for how `max_variance` is applied.
+    pub async fn read_averaged(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+        n: u32,
+        max_variance: Option<u64>,
+    ) -> Result<i32, Error> {
+        let mut accumulator = Accumulator::new();
+
+        for _ in 0..n.max(1) {
+            accumulator.push(self.read_calibrated(delay, channel).await.corrected);
+        }
+
+        self.gate(&accumulator, max_variance)
+    }
+
+    /// Averages `n` reads (at least one) from the given channel asynchronously
+    /// without applying any corrections
+    async fn average(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+        n: u32,
+    ) -> i64 {
+        let n = n.max(1);
+        let mut sum = 0i64;
+
+        for _ in 0..n {
+            sum += sign_extend(self.read_channel(delay, channel).await, M::BITS) as i64;
+        }
+
+        sum / n as i64
+    }
+
+    /// Switches the mux to the given channel, throws away any conversions
+    /// required for the input to settle, and then reads a raw frame from it
+    async fn read_channel(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+    ) -> u32 {
+        self.select_channel(delay, channel).await;
+        self.read_internal(delay, None).await
+    }
+
+    /// Switches the mux to the given channel and throws away any conversions
+    /// required for the input to settle, returning whether the channel changed
+    /// and how many conversions were thrown away
+    async fn select_channel(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+    ) -> (bool, u32) {
+        let old_channel = self.set_channel(channel);
+
+        // Wait for DRDY setup time if we changed the channel
+        if old_channel != Some(channel) {
+            #[cfg(feature = "timing-check")]
+            let setup_start = self.clock.now_us();
+
+            delay.delay_us(DRDY_SETUP_US).await;
+
+            #[cfg(feature = "timing-check")]
+            self.check_drdy_setup(setup_start);
+        }
+
+        let discards = match old_channel {
+            Some(old_channel) => M::settling_discards(old_channel, channel),
+            None => M::MAX_SETTLING_DISCARDS,
+        };
+
+        for _ in 0..discards {
+            self.read_internal(delay, None).await;
+        }
+
+        (old_channel != Some(channel), discards)
+    }
+
+    /// Reads from the given channel asynchronously and returns the conversion
+    /// result both as it was read and with the channel's software offset,
+    /// system gain, and linearity corrections applied
+    pub async fn read_calibrated(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+    ) -> CalibratedReading {
+        let raw = sign_extend(self.read_channel(delay, channel).await, M::BITS);
+
+        CalibratedReading {
+            raw,
+            corrected: self.correct(channel, raw),
+        }
     }
 
-    /// Sets SCLK low, waits for DRDY to go low (blocking), and then sets SCLK
-    /// high to initiate standby mode (will take 12ms when SPEED is high and
-    /// 99ms when speed is low to actually initiate standby)
-    pub fn enter_standby_blocking(&mut self) {
-        self.sclk.set_low().unwrap();
+    /// Same as [`Self::read_calibrated`], except that frames the chip may have
+    /// stopped shifting out half way are rejected with [`Error::AbortedFrame`]
+    ///
+    /// About half of these reads wait for up to one and a half conversion
+    /// periods longer than a regular read. See [`Self::read_checked_blocking`]
+    /// for details.
+    pub async fn read_checked(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+    ) -> Result<CalibratedReading, Error> {
+        let data = self.read_channel(delay, channel).await;
+        self.check_wiring()?;
+
+        if data & 1 != 0 {
+            let timeout_us = self.config.speed.conversion_period_us() * 3 / 2;
+            let alive = with_timeout(self.wait_for_drdy_edge(false), delay.delay_us(timeout_us))
+                .await
+                .is_some();
+            self.confirm_frame(alive)?;
+        }
 
-        while self.dout.is_high().unwrap() {}
+        let raw = sign_extend(data, M::BITS);
 
-        self.sclk.set_high().unwrap();
+        Ok(CalibratedReading {
+            raw,
+            corrected: self.correct(channel, raw),
+        })
     }
 
-    /// Sets SCLK low, waits for DRDY to go low (blocking), and then pulses the
-    /// SCLK to extract the data from DOUT
+    /// Reads from the given channel asynchronously and returns the conversion
+    /// result with the channel's corrections applied, without clamping it to
+    /// the range of an i32
     ///
-    /// This operation automatically exits standby mode and the first available
-    /// data is guaranteed to be valid
-    fn read_internal_blocking(&mut self, delay: &mut impl DelayNs) -> i32 {
-        self.sclk.set_low().unwrap();
+    /// See [`Self::read_wide_blocking`] for details.
+    pub async fn read_wide(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+    ) -> i64 {
+        let raw = sign_extend(self.read_channel(delay, channel).await, M::BITS);
 
-        while self.dout.is_high().unwrap() {}
+        self.correct_wide(channel, raw)
+    }
 
-        let mut data = 0u32;
+    /// Reads from the given channel asynchronously like
+    /// [`Self::read_calibrated`], and also reports what the driver had to do
+    /// to obtain the reading
+    ///
+    /// See [`Self::read_with_status_blocking`] for details.
+    pub async fn read_with_status(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+    ) -> ReadStatus {
+        let start = self.clock.now_us();
+        let exited_standby = self.power_state == PowerState::Standby;
+        let drdy_wait_before = self.metrics.total_drdy_wait_us;
+        let overruns_before = self.metrics.overruns;
+
+        let (channel_changed, discarded) = self.select_channel(delay, channel).await;
+        let raw = sign_extend(self.read_internal(delay, None).await, M::BITS);
+
+        ReadStatus {
+            reading: CalibratedReading {
+                raw,
+                corrected: self.correct(channel, raw),
+            },
+            exited_standby,
+            channel_changed,
+            discarded,
+            drdy_wait_us: self
+                .metrics
+                .total_drdy_wait_us
+                .wrapping_sub(drdy_wait_before),
+            duration_us: self.clock.now_us().saturating_sub(start),
+            overruns: self.metrics.overruns.wrapping_sub(overruns_before),
+        }
+    }
 
-        for _ in 0..24 {
-            self.sclk.set_high().unwrap();
-            delay.delay_ns(50);
+    /// Reads asynchronously from the channel set with
+    /// [`Self::set_default_channel`] and returns the corrected value
+    pub async fn read_default(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> i32 {
+        let channel = self.default_channel;
+        self.read_calibrated(delay, channel).await.corrected
+    }
 
-            data |= self.dout.is_high().unwrap() as u32;
-            data <<= 1;
+    /// Reads each of the given channels in order asynchronously and writes the
+    /// corrected values into `out`, returning the number of values written
+    ///
+    /// See [`Self::read_sequence_blocking`] for details.
+    pub async fn read_sequence(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channels: &[M::Channel],
+        out: &mut [i32],
+    ) -> usize {
+        let n = channels.len().min(out.len());
 
-            delay.delay_ns(50);
-            self.sclk.set_low().unwrap();
-            delay.delay_ns(100);
+        for (&channel, value) in channels.iter().zip(out.iter_mut()) {
+            *value = self.read_calibrated(delay, channel).await.corrected;
         }
 
-        // Pulse SCLK a 25th time to force DRDY high
-        self.sclk.set_high().unwrap();
-        delay.delay_ns(100);
-        self.sclk.set_low().unwrap();
-        delay.delay_ns(100);
-
-        i24_to_i32(data)
+        n
     }
-}
 
-#[cfg(feature = "embedded-hal-async")]
-impl<DOUT, SCLK, PWDN, A0, A1, M> ADS123X<DOUT, SCLK, PWDN, A0, A1, M>
-where
-    DOUT: InputPin + embedded_hal_async::digital::Wait,
-    SCLK: OutputPin,
-    PWDN: OutputPin,
-    A0: StatefulOutputPin,
-    A1: StatefulOutputPin,
-    M: ADSModel,
-{
-    /// Sets PWDN low, waits for the AVDD voltage to stabilize, then pulses PWDN
-    /// once before setting it high
-    pub async fn reset(&mut self, delay: &mut impl embedded_hal_async::delay::DelayNs) {
-        self.pwdn.set_low().unwrap();
+    /// Wakes the chip if it is in standby, reads `n` consecutive conversions
+    /// from the given channel into `out` asynchronously, and enters standby
+    /// again, returning the number of values written
+    ///
+    /// See [`Self::capture_burst_then_standby_blocking`] for details.
+    pub async fn capture_burst_then_standby(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+        n: usize,
+        out: &mut [i32],
+    ) -> Result<usize, Error> {
+        if self.power_state == PowerState::Standby {
+            self.wake(delay).await?;
+        }
 
-        // Wait for AVDD to stabilize (we can't easily measure this so we just
-        // wait for a predefined amount of time that should be fine)
-        delay.delay_us(50).await;
+        let n = n.min(out.len());
 
-        self.pwdn.set_high().unwrap();
-        delay.delay_us(26).await;
+        for value in out[..n].iter_mut() {
+            *value = self.read_calibrated(delay, channel).await.corrected;
+        }
 
-        self.pwdn.set_low().unwrap();
-        delay.delay_us(26).await;
+        self.enter_standby().await;
 
-        self.pwdn.set_high().unwrap();
+        Ok(n)
     }
 
-    /// Sets SCLK low, waits for DRDY to go low, and then pulses the SCLK 26
-    /// times to initiate calibration offset mode
-    pub async fn calibrate_offset(&mut self, delay: &mut impl embedded_hal_async::delay::DelayNs) {
-        let _ = self.read_internal(delay).await;
+    /// Reads consecutive conversions from the given channel asynchronously
+    /// into `out` until the driver's [`Clock`] reaches `deadline_us` or the
+    /// buffer is full
+    ///
+    /// See [`Self::capture_until_blocking`] for details.
+    pub async fn capture_until(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+        deadline_us: u64,
+        out: &mut [TimestampedReading],
+    ) -> CaptureSummary {
+        let period_us = self.config.speed.conversion_period_us();
+        let mut summary = CaptureSummary::default();
+        let mut previous_us = None;
+
+        for slot in out.iter_mut() {
+            if self.clock.now_us() >= deadline_us {
+                break;
+            }
+
+            let value = self.read_calibrated(delay, channel).await.corrected;
+            let timestamp_us = self.clock.now_us();
 
-        // Pulse SCLK a 26th time to start calibration
-        self.sclk.set_high().unwrap();
-        delay.delay_ns(100).await;
-        self.sclk.set_low().unwrap();
+            *slot = TimestampedReading {
+                value,
+                timestamp_us,
+            };
+            summary.record(timestamp_us, previous_us, period_us);
+            previous_us = Some(timestamp_us);
+        }
 
-        // Wait for DRDY to go low again which signals that calibration is
-        // complete
-        self.dout.wait_for_high().await.unwrap();
+        summary
     }
 
-    /// Sets SCLK low, waits for DRDY to go low, and then sets SCLK high to
-    /// initiate standby mode (will take 12ms when SPEED is high and 99ms when
-    /// speed is low to actually initiate standby)
-    pub async fn enter_standby(&mut self) {
-        self.sclk.set_low().unwrap();
-        self.dout.wait_for_high().await.unwrap();
-        self.sclk.set_high().unwrap();
+    /// Reads consecutive conversions from the given channel asynchronously
+    /// into `out` until the driver's [`Clock`] reaches `deadline` or the
+    /// buffer is full, see [`Self::capture_until_blocking`]
+    #[cfg(feature = "fugit")]
+    pub async fn capture_until_instant(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: M::Channel,
+        deadline: fugit::TimerInstantU64<1_000_000>,
+        out: &mut [TimestampedReading],
+    ) -> CaptureSummary {
+        self.capture_until(delay, channel, deadline.ticks(), out)
+            .await
     }
 
     /// Sets SCLK low, waits for DRDY to go low, and then pulses the SCLK to
     /// extract the data from DOUT
     ///
     /// This operation automatically exits standby mode and the first available
-    /// data is guaranteed to be valid
-    async fn read_internal(&mut self, delay: &mut impl embedded_hal_async::delay::DelayNs) -> i32 {
-        self.sclk.set_low().unwrap();
-
-        self.dout.wait_for_low().await.unwrap();
-
-        let mut data = 0u32;
+    /// data is guaranteed to be valid. The raw frame is returned right aligned
+    /// and without sign extension.
+    async fn read_internal(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        on_bit: Option<&mut dyn FnMut(u32, PinState, u64)>,
+    ) -> u32 {
+        let pulses = self.read_pulses();
+        self.shift_frame(delay, pulses, on_bit).await
+    }
 
-        for _ in 0..24 {
-            self.sclk.set_high().unwrap();
-            delay.delay_ns(50).await;
+    /// Waits for DOUT to signal a conversion and returns whether it had to
+    ///
+    /// DOUT is sampled once up front: if it is already low, the conversion
+    /// behind it hasn't been read yet and is taken as is. Otherwise this waits
+    /// for the falling edge rather than the low level, so that an
+    /// interrupt-driven pin wakes the task once per conversion. With `stale`
+    /// set, the level DOUT has was left behind by the previous frame and only
+    /// the next falling edge counts.
+    ///
+    /// A conversion that completes between the sample and the edge wait being
+    /// armed is missed, which costs one conversion period but never returns old
+    /// data.
+    async fn wait_for_drdy_edge(&mut self, stale: bool) -> bool {
+        if !stale && self.dout.is_low().unwrap() {
+            return false;
+        }
 
-            data |= self.dout.is_high().unwrap() as u32;
-            data <<= 1;
+        self.dout.wait_for_falling_edge().await.unwrap();
+        true
+    }
 
-            delay.delay_ns(50).await;
-            self.sclk.set_low().unwrap();
-            delay.delay_ns(100).await;
+    /// Carries out the actions of a [`FrameReader`] that pulses SCLK `pulses`
+    /// times in total
+    async fn shift_frame(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        pulses: u32,
+        mut on_bit: Option<&mut dyn FnMut(u32, PinState, u64)>,
+    ) -> u32 {
+        let start = self.clock.now_us();
+        let mut reader = self.frame_reader(pulses);
+        let mut timestamp = 0;
+
+        let mut stale = false;
+        let mut dout_went_high = false;
+
+        #[cfg(feature = "timing-check")]
+        let mut drdy_us = start;
+
+        loop {
+            match reader.next_action() {
+                Action::SetSclk(state) => self.sclk.set_state(state).unwrap(),
+                Action::Delay(ns) => delay.delay_ns(ns).await,
+                // Folded into the DRDY wait, which then only accepts the edge
+                // that follows the update
+                Action::WaitForUpdate => stale = true,
+                Action::WaitForDrdy => {
+                    self.start_conversion();
+                    let pending = self.wait_for_drdy_edge(stale).await;
+                    self.record_drdy_latency(start, pending);
+                    dout_went_high |= pending;
+
+                    #[cfg(feature = "timing-check")]
+                    {
+                        drdy_us = self.clock.now_us();
+                    }
+                }
+                Action::SampleDout => {
+                    reader.sampled(self.dout.is_high().unwrap());
+                    timestamp = self.bit_timestamp(&on_bit);
+                }
+                Action::BitRead { index, level } => {
+                    if let Some(on_bit) = on_bit.as_mut() {
+                        on_bit(index, level, timestamp);
+                    }
+                }
+                Action::Done(data) => {
+                    #[cfg(feature = "timing-check")]
+                    self.check_frame_timing(drdy_us, pulses);
+
+                    // A trailing pulse forces DOUT high on a working chip
+                    if pulses > M::BITS {
+                        dout_went_high |= self.dout.is_high().unwrap();
+                    }
+
+                    self.finish_conversion(data, start, dout_went_high);
+                    return data;
+                }
+            }
         }
+    }
+}
 
-        // Pulse SCLK a 25th time to force DRDY high
-        self.sclk.set_high().unwrap();
-        delay.delay_ns(100).await;
-        self.sclk.set_low().unwrap();
-        delay.delay_ns(100).await;
+/// Runs `future` until it completes or `timeout` elapses, whichever happens
+/// first, returning `None` on timeout
+#[cfg(feature = "embedded-hal-async")]
+async fn with_timeout<F: core::future::Future>(
+    future: F,
+    timeout: impl core::future::Future<Output = ()>,
+) -> Option<F::Output> {
+    use core::task::Poll;
+
+    let mut future = core::pin::pin!(future);
+    let mut timeout = core::pin::pin!(timeout);
+
+    core::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
 
-        i24_to_i32(data)
-    }
+        match timeout.as_mut().poll(cx) {
+            Poll::Ready(()) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    })
+    .await
 }
 
-fn i24_to_i32(value: u32) -> i32 {
-    // Mask to get the lower 24 bits
-    let masked_value = value & 0xFFFFFF;
+/// Interprets the lower `bits` bits of `value` as a two's complement number
+fn sign_extend(value: u32, bits: u32) -> i32 {
+    // Move the sign bit of the frame into the sign bit of the i32, then use an
+    // arithmetic shift to bring the value back down while copying the sign
+    let shift = 32 - bits;
 
-    // Check if the 24th bit (sign bit) is set
-    if masked_value & 0x800000 != 0 {
-        // If so, subtract 2^24 to convert to negative
-        masked_value as i32 - (1 << 24)
-    } else {
-        // Otherwise, just return the value as a positive i32
-        masked_value as i32
-    }
+    ((value << shift) as i32) >> shift
 }
 
 /* ======== ADS1232 ======== */
 
+#[cfg(feature = "ads1232")]
 pub struct ADS1232;
 
-impl private::Sealed for ADS1232 {}
-impl ADSModel for ADS1232 {}
+#[cfg(feature = "ads1232")]
+#[cfg(not(feature = "unstable-custom-model"))]
+impl private::SealedModel for ADS1232 {}
+#[cfg(feature = "ads1232")]
+impl ADSModel for ADS1232 {
+    const BITS: u32 = 24;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    type Channel = ADS1232Channel;
+
+    const CHANNELS: &'static [ADS1232Channel] = &ADS1232Channel::ALL;
+
+    fn channel_index(channel: ADS1232Channel) -> usize {
+        channel as usize
+    }
+
+    fn mux_state(channel: ADS1232Channel) -> (PinState, PinState) {
+        // The second mux pin is labelled TEMP on the ADS1232
+        match channel {
+            ADS1232Channel::AIN1 => (PinState::Low, PinState::Low),
+            ADS1232Channel::AIN2 => (PinState::High, PinState::Low),
+            ADS1232Channel::Temp => (PinState::Low, PinState::High),
+        }
+    }
+
+    fn decode_mux(a0: bool, temp: bool) -> Option<ADS1232Channel> {
+        // The temperature sensor is only selected with A0 low, so A0 high with
+        // TEMP high doesn't select a valid input
+        match (a0, temp) {
+            (false, false) => Some(ADS1232Channel::AIN1),
+            (true, false) => Some(ADS1232Channel::AIN2),
+            (false, true) => Some(ADS1232Channel::Temp),
+            (true, true) => None,
+        }
+    }
+
+    fn settling_discards(from: ADS1232Channel, to: ADS1232Channel) -> u32 {
+        // Throw away 4 conversions if we changed the value of the TEMP pin
+        // (Datasheet section 8.3.7)
+        if (from == ADS1232Channel::Temp) != (to == ADS1232Channel::Temp) {
+            4
+        } else {
+            0
+        }
+    }
+
+    const MAX_SETTLING_DISCARDS: u32 = 4;
+}
+
+#[cfg(feature = "ads1232")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ADS1232Channel {
+    #[default]
     AIN1,
     AIN2,
     /// Reads from the internal temperature sensor
@@ -253,6 +3444,45 @@ pub enum ADS1232Channel {
     Temp,
 }
 
+#[cfg(feature = "ads1232")]
+impl ADS1232Channel {
+    /// Every channel, in index order
+    pub const ALL: [ADS1232Channel; 3] = [
+        ADS1232Channel::AIN1,
+        ADS1232Channel::AIN2,
+        ADS1232Channel::Temp,
+    ];
+
+    /// Returns an iterator over every channel, in index order
+    pub fn iter() -> impl Iterator<Item = ADS1232Channel> {
+        Self::ALL.into_iter()
+    }
+}
+
+#[cfg(feature = "ads1232")]
+impl From<ADS1232Channel> for u8 {
+    fn from(channel: ADS1232Channel) -> u8 {
+        channel as u8
+    }
+}
+
+#[cfg(feature = "ads1232")]
+impl TryFrom<u8> for ADS1232Channel {
+    type Error = Error;
+
+    /// Converts a channel index (0 for AIN1) into a channel, failing with
+    /// [`Error::InvalidChannel`] for out of range values
+    fn try_from(index: u8) -> Result<Self, Error> {
+        match index {
+            0 => Ok(ADS1232Channel::AIN1),
+            1 => Ok(ADS1232Channel::AIN2),
+            2 => Ok(ADS1232Channel::Temp),
+            _ => Err(Error::InvalidChannel),
+        }
+    }
+}
+
+#[cfg(feature = "ads1232")]
 impl ADS1232 {
     pub fn new<DOUT, SCLK, PWDN, A0, A1>(
         dout: DOUT,
@@ -268,48 +3498,26 @@ impl ADS1232 {
         A0: StatefulOutputPin,
         A1: StatefulOutputPin,
     {
-        ADS123X::new(dout, sclk, pwdn, a0, a1)
+        ADS123X::new(dout, sclk, pwdn, a0, a1, NoClock, NoHooks)
     }
 }
 
-impl<DOUT, SCLK, PWDN, A0, A1> ADS123X<DOUT, SCLK, PWDN, A0, A1, ADS1232>
+#[cfg(feature = "ads1232")]
+impl<DOUT, SCLK, PWDN, A0, A1, C, H, T> ADS123X<DOUT, SCLK, PWDN, A0, A1, ADS1232, C, H, T>
 where
     DOUT: InputPin,
     SCLK: OutputPin,
     PWDN: OutputPin,
     A0: StatefulOutputPin,
     A1: StatefulOutputPin,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
 {
-    /// Sets the new state if it is different from the old state and returns the
-    /// old state
-    #[must_use]
-    fn set_channel(&mut self, channel: ADS1232Channel) -> ADS1232Channel {
-        let old_channel = match (
-            self.a0.is_set_high().unwrap(),
-            self.a1.is_set_high().unwrap(),
-        ) {
-            (true, false) => ADS1232Channel::AIN1,
-            (false, false) => ADS1232Channel::AIN2,
-            (_, true) => ADS1232Channel::Temp,
-        };
-
-        if channel != old_channel {
-            let (a0, temp) = match channel {
-                ADS1232Channel::AIN1 => (PinState::Low, PinState::Low),
-                ADS1232Channel::AIN2 => (PinState::High, PinState::Low),
-                ADS1232Channel::Temp => (PinState::Low, PinState::High),
-            };
-
-            self.a0.set_state(a0).unwrap();
-            self.a1.set_state(temp).unwrap();
-        }
-
-        old_channel
-    }
-
     /// Reads data from the given ADS channel and returns the value decoded as
-    /// an i32. If the chip was previously in standby mode, this will exit
-    /// standby mode.
+    /// an i32, with the channel's software offset, system gain, and linearity
+    /// corrections applied. If the chip was previously in standby mode, this
+    /// will exit standby mode.
     ///
     /// Callers of this function should note that it may block for an
     /// extended period of time (several hundred ms) depending on the configured
@@ -320,37 +3528,51 @@ where
     /// penalty as 4 conversions must be thrown away before the value is
     /// considered settled.
     pub fn read_blocking(&mut self, delay: &mut impl DelayNs, channel: ADS1232Channel) -> i32 {
-        let old_channel = self.set_channel(channel);
+        self.read_calibrated_blocking(delay, channel).corrected
+    }
 
-        // Wait for DRDY setup time if we changed the channel
-        if old_channel != channel {
-            delay.delay_us(50);
-        }
+    /// Performs the same operation as [`Self::read_blocking`], but returns the
+    /// frame exactly as it was clocked out of DOUT (MSB first, right aligned,
+    /// and without sign extension or any corrections)
+    pub fn read_raw_blocking(&mut self, delay: &mut impl DelayNs, channel: ADS1232Channel) -> u32 {
+        self.read_channel_blocking(delay, channel)
+    }
 
-        // Throw away 4 conversions if we changed the value of the TEMP pin
-        // (Datasheet section 8.3.7)
-        if (old_channel == ADS1232Channel::Temp) != (channel == ADS1232Channel::Temp) {
-            for _ in 0..4 {
-                self.read_internal_blocking(delay);
-            }
-        }
+    /// Reads from the internal temperature sensor and then switches the mux
+    /// back to the previously selected input, so that a periodic temperature
+    /// poll doesn't leave the next regular read of that input unsettled
+    ///
+    /// Both switches throw away the 4 conversions required for the input to
+    /// settle, so this blocks for at least 9 conversion periods when another
+    /// input was selected. If the mux was in an invalid state the default
+    /// channel (see [`Self::set_default_channel`]) is selected afterwards.
+    pub fn read_temperature_and_restore_blocking(&mut self, delay: &mut impl DelayNs) -> i32 {
+        let previous = self.selected_channel().unwrap_or(self.default_channel);
+
+        let temperature = self.read_blocking(delay, ADS1232Channel::Temp);
+        self.select_channel_blocking(delay, previous);
 
-        self.read_internal_blocking(delay)
+        temperature
     }
 }
 
 #[cfg(feature = "embedded-hal-async")]
-impl<DOUT, SCLK, PWDN, A0, A1> ADS123X<DOUT, SCLK, PWDN, A0, A1, ADS1232>
+#[cfg(feature = "ads1232")]
+impl<DOUT, SCLK, PWDN, A0, A1, C, H, T> ADS123X<DOUT, SCLK, PWDN, A0, A1, ADS1232, C, H, T>
 where
     DOUT: InputPin + embedded_hal_async::digital::Wait,
     SCLK: OutputPin,
     PWDN: OutputPin,
     A0: StatefulOutputPin,
     A1: StatefulOutputPin,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
 {
     /// Reads data from the given ADS channel asynchronously and returns the
-    /// value decoded as an i32. If the chip was previously in standby mode,
-    /// this will exit standby mode.
+    /// value decoded as an i32, with the channel's software offset, system
+    /// gain, and linearity corrections applied. If the chip was previously in
+    /// standby mode, this will exit standby mode.
     ///
     /// Callers of this function should note that it may take an extended period
     /// of time (several hundred ms) for the future to resolve depending on the
@@ -365,41 +3587,134 @@ where
         delay: &mut impl embedded_hal_async::delay::DelayNs,
         channel: ADS1232Channel,
     ) -> i32 {
-        let old_channel = self.set_channel(channel);
+        self.read_calibrated(delay, channel).await.corrected
+    }
 
-        // Wait for DRDY setup time if we changed the channel
-        if old_channel != channel {
-            delay.delay_us(50).await;
-        }
+    /// Performs the same operation as [`Self::read`], but returns the frame
+    /// exactly as it was clocked out of DOUT (MSB first, right aligned, and
+    /// without sign extension or any corrections)
+    pub async fn read_raw(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: ADS1232Channel,
+    ) -> u32 {
+        self.read_channel(delay, channel).await
+    }
 
-        // Throw away 4 conversions if we changed the value of the TEMP pin
-        // (Datasheet section 8.3.7)
-        if (old_channel == ADS1232Channel::Temp) != (channel == ADS1232Channel::Temp) {
-            for _ in 0..4 {
-                self.read_internal(delay).await;
-            }
-        }
+    /// Reads from the internal temperature sensor asynchronously and then
+    /// switches the mux back to the previously selected input
+    ///
+    /// See [`Self::read_temperature_and_restore_blocking`] for details.
+    pub async fn read_temperature_and_restore(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> i32 {
+        let previous = self.selected_channel().unwrap_or(self.default_channel);
 
-        self.read_internal(delay).await
+        let temperature = self.read(delay, ADS1232Channel::Temp).await;
+        self.select_channel(delay, previous).await;
+
+        temperature
     }
 }
 
 /* ======== ADS1234 ======== */
 
+#[cfg(feature = "ads1234")]
 pub struct ADS1234;
 
-impl private::Sealed for ADS1234 {}
-impl ADSModel for ADS1234 {}
+#[cfg(feature = "ads1234")]
+#[cfg(not(feature = "unstable-custom-model"))]
+impl private::SealedModel for ADS1234 {}
+#[cfg(feature = "ads1234")]
+impl ADSModel for ADS1234 {
+    const BITS: u32 = 24;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    type Channel = ADS1234Channel;
+
+    const CHANNELS: &'static [ADS1234Channel] = &ADS1234Channel::ALL;
+
+    fn channel_index(channel: ADS1234Channel) -> usize {
+        channel as usize
+    }
+
+    fn mux_state(channel: ADS1234Channel) -> (PinState, PinState) {
+        match channel {
+            ADS1234Channel::AIN1 => (PinState::Low, PinState::Low),
+            ADS1234Channel::AIN2 => (PinState::High, PinState::Low),
+            ADS1234Channel::AIN3 => (PinState::Low, PinState::High),
+            ADS1234Channel::AIN4 => (PinState::High, PinState::High),
+        }
+    }
+
+    fn decode_mux(a0: bool, a1: bool) -> Option<ADS1234Channel> {
+        match (a0, a1) {
+            (false, false) => Some(ADS1234Channel::AIN1),
+            (true, false) => Some(ADS1234Channel::AIN2),
+            (false, true) => Some(ADS1234Channel::AIN3),
+            (true, true) => Some(ADS1234Channel::AIN4),
+        }
+    }
+
+    fn settling_discards(_from: ADS1234Channel, _to: ADS1234Channel) -> u32 {
+        0
+    }
+
+    const MAX_SETTLING_DISCARDS: u32 = 0;
+}
+
+#[cfg(feature = "ads1234")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ADS1234Channel {
+    #[default]
     AIN1,
     AIN2,
     AIN3,
     AIN4,
 }
 
+#[cfg(feature = "ads1234")]
+impl ADS1234Channel {
+    /// Every channel, in index order
+    pub const ALL: [ADS1234Channel; 4] = [
+        ADS1234Channel::AIN1,
+        ADS1234Channel::AIN2,
+        ADS1234Channel::AIN3,
+        ADS1234Channel::AIN4,
+    ];
+
+    /// Returns an iterator over every channel, in index order
+    pub fn iter() -> impl Iterator<Item = ADS1234Channel> {
+        Self::ALL.into_iter()
+    }
+}
+
+#[cfg(feature = "ads1234")]
+impl From<ADS1234Channel> for u8 {
+    fn from(channel: ADS1234Channel) -> u8 {
+        channel as u8
+    }
+}
+
+#[cfg(feature = "ads1234")]
+impl TryFrom<u8> for ADS1234Channel {
+    type Error = Error;
+
+    /// Converts a channel index (0 for AIN1) into a channel, failing with
+    /// [`Error::InvalidChannel`] for out of range values
+    fn try_from(index: u8) -> Result<Self, Error> {
+        match index {
+            0 => Ok(ADS1234Channel::AIN1),
+            1 => Ok(ADS1234Channel::AIN2),
+            2 => Ok(ADS1234Channel::AIN3),
+            3 => Ok(ADS1234Channel::AIN4),
+            _ => Err(Error::InvalidChannel),
+        }
+    }
+}
+
+#[cfg(feature = "ads1234")]
 impl ADS1234 {
     pub fn new<DOUT, SCLK, PWDN, A0, A1>(
         dout: DOUT,
@@ -415,75 +3730,59 @@ impl ADS1234 {
         A0: StatefulOutputPin,
         A1: StatefulOutputPin,
     {
-        ADS123X::new(dout, sclk, pwdn, a0, a1)
+        ADS123X::new(dout, sclk, pwdn, a0, a1, NoClock, NoHooks)
     }
 }
 
-impl<DOUT, SCLK, PWDN, A0, A1> ADS123X<DOUT, SCLK, PWDN, A0, A1, ADS1234>
+#[cfg(feature = "ads1234")]
+impl<DOUT, SCLK, PWDN, A0, A1, C, H, T> ADS123X<DOUT, SCLK, PWDN, A0, A1, ADS1234, C, H, T>
 where
     DOUT: InputPin,
     SCLK: OutputPin,
     PWDN: OutputPin,
     A0: StatefulOutputPin,
     A1: StatefulOutputPin,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
 {
-    fn set_channel(&mut self, channel: ADS1234Channel) -> ADS1234Channel {
-        let old_channel = match (
-            self.a0.is_set_high().unwrap(),
-            self.a1.is_set_high().unwrap(),
-        ) {
-            (false, false) => ADS1234Channel::AIN1,
-            (true, false) => ADS1234Channel::AIN2,
-            (false, true) => ADS1234Channel::AIN3,
-            (true, true) => ADS1234Channel::AIN4,
-        };
-
-        if old_channel != channel {
-            let (a0, a1) = match channel {
-                ADS1234Channel::AIN1 => (PinState::Low, PinState::Low),
-                ADS1234Channel::AIN2 => (PinState::High, PinState::Low),
-                ADS1234Channel::AIN3 => (PinState::Low, PinState::High),
-                ADS1234Channel::AIN4 => (PinState::High, PinState::High),
-            };
-
-            self.a0.set_state(a0).unwrap();
-            self.a1.set_state(a1).unwrap();
-        }
-
-        old_channel
-    }
-
     /// Reads data from the given ADS channel and returns the value decoded as
-    /// an i32. If the chip was previously in standby mode, this will exit
-    /// standby mode.
+    /// an i32, with the channel's software offset, system gain, and linearity
+    /// corrections applied. If the chip was previously in standby mode, this
+    /// will exit standby mode.
     ///
     /// Callers of this function should note that it may block for an
     /// extended period of time (several hundred ms) depending on the configured
     /// SPEED if waking up from standby mode.
     pub fn read_blocking(&mut self, delay: &mut impl DelayNs, channel: ADS1234Channel) -> i32 {
-        let old_channel = self.set_channel(channel);
-
-        // Wait for DRDY setup time if we changed the channel
-        if old_channel != channel {
-            delay.delay_us(50);
-        }
+        self.read_calibrated_blocking(delay, channel).corrected
+    }
 
-        self.read_internal_blocking(delay)
+    /// Performs the same operation as [`Self::read_blocking`], but returns the
+    /// frame exactly as it was clocked out of DOUT (MSB first, right aligned,
+    /// and without sign extension or any corrections)
+    pub fn read_raw_blocking(&mut self, delay: &mut impl DelayNs, channel: ADS1234Channel) -> u32 {
+        self.read_channel_blocking(delay, channel)
     }
 }
 
 #[cfg(feature = "embedded-hal-async")]
-impl<DOUT, SCLK, PWDN, A0, A1> ADS123X<DOUT, SCLK, PWDN, A0, A1, ADS1234>
+#[cfg(feature = "ads1234")]
+impl<DOUT, SCLK, PWDN, A0, A1, C, H, T> ADS123X<DOUT, SCLK, PWDN, A0, A1, ADS1234, C, H, T>
 where
     DOUT: InputPin + embedded_hal_async::digital::Wait,
     SCLK: OutputPin,
     PWDN: OutputPin,
     A0: StatefulOutputPin,
     A1: StatefulOutputPin,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
 {
     /// Reads data from the given ADS channel asynchronously and returns the
-    /// value decoded as an i32. If the chip was previously in standby mode,
-    /// this will exit standby mode.
+    /// value decoded as an i32, with the channel's software offset, system
+    /// gain, and linearity corrections applied. If the chip was previously in
+    /// standby mode, this will exit standby mode.
     ///
     /// Callers of this function should note that it may take an extended period
     /// of time (several hundred ms) for the future to resolve depending on the
@@ -493,13 +3792,154 @@ where
         delay: &mut impl embedded_hal_async::delay::DelayNs,
         channel: ADS1234Channel,
     ) -> i32 {
-        let old_channel = self.set_channel(channel);
+        self.read_calibrated(delay, channel).await.corrected
+    }
 
-        // Wait for DRDY setup time if we changed the channel
-        if old_channel != channel {
-            delay.delay_us(50).await;
+    /// Performs the same operation as [`Self::read`], but returns the frame
+    /// exactly as it was clocked out of DOUT (MSB first, right aligned, and
+    /// without sign extension or any corrections)
+    pub async fn read_raw(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        channel: ADS1234Channel,
+    ) -> u32 {
+        self.read_channel(delay, channel).await
+    }
+}
+
+/// Defines a single-channel model, which has no mux pins and only differs
+/// from the other single-channel models in the width of its frame
+macro_rules! single_channel_model {
+    ($(#[$attr:meta])* $name:ident, $bits:literal) => {
+        $(#[$attr])*
+        pub struct $name;
+
+        #[cfg(not(feature = "unstable-custom-model"))]
+        impl private::SealedModel for $name {}
+        impl ADSModel for $name {
+            const BITS: u32 = $bits;
+
+            type Channel = ();
+
+            const CHANNELS: &'static [()] = &[()];
+
+            fn channel_index(_channel: ()) -> usize {
+                0
+            }
+
+            fn mux_state(_channel: ()) -> (PinState, PinState) {
+                (PinState::Low, PinState::Low)
+            }
+
+            fn decode_mux(_a0: bool, _a1: bool) -> Option<()> {
+                Some(())
+            }
+
+            fn settling_discards(_from: (), _to: ()) -> u32 {
+                0
+            }
+
+            const MAX_SETTLING_DISCARDS: u32 = 0;
         }
 
-        self.read_internal(delay).await
-    }
+        impl $name {
+            pub fn new<DOUT, SCLK, PWDN>(
+                dout: DOUT,
+                sclk: SCLK,
+                pwdn: PWDN,
+            ) -> ADS123X<DOUT, SCLK, PWDN, NoPin, NoPin, Self>
+            where
+                DOUT: InputPin,
+                SCLK: OutputPin,
+                PWDN: OutputPin,
+            {
+                ADS123X::new(dout, sclk, pwdn, NoPin, NoPin, NoClock, NoHooks)
+            }
+        }
+
+        impl<DOUT, SCLK, PWDN, C, H, T> ADS123X<DOUT, SCLK, PWDN, NoPin, NoPin, $name, C, H, T>
+        where
+            DOUT: InputPin,
+            SCLK: OutputPin,
+            PWDN: OutputPin,
+            C: Clock,
+            H: AdsHooks,
+            T: TimingProfile,
+        {
+            /// Reads data from the ADS and returns the value decoded as an i32,
+            /// with the channel's software offset, system gain, and linearity
+            /// corrections applied. If the chip was previously in standby mode,
+            /// this will exit standby mode.
+            ///
+            /// Callers of this function should note that it may block for an
+            /// extended period of time (several hundred ms) depending on the
+            /// configured SPEED if waking up from standby mode.
+            pub fn read_blocking(&mut self, delay: &mut impl DelayNs) -> i32 {
+                self.read_calibrated_blocking(delay, ()).corrected
+            }
+
+            /// Performs the same operation as [`Self::read_blocking`], but
+            /// returns the frame exactly as it was clocked out of DOUT (MSB
+            /// first, right aligned, and without sign extension or any
+            /// corrections)
+            pub fn read_raw_blocking(&mut self, delay: &mut impl DelayNs) -> u32 {
+                self.read_channel_blocking(delay, ())
+            }
+        }
+
+        #[cfg(feature = "embedded-hal-async")]
+        impl<DOUT, SCLK, PWDN, C, H, T> ADS123X<DOUT, SCLK, PWDN, NoPin, NoPin, $name, C, H, T>
+        where
+            DOUT: InputPin + embedded_hal_async::digital::Wait,
+            SCLK: OutputPin,
+            PWDN: OutputPin,
+            C: Clock,
+            H: AdsHooks,
+            T: TimingProfile,
+        {
+            /// Reads data from the ADS asynchronously and returns the value
+            /// decoded as an i32, with the channel's software offset, system
+            /// gain, and linearity corrections applied. If the chip was
+            /// previously in standby mode, this will exit standby mode.
+            ///
+            /// Callers of this function should note that it may take an
+            /// extended period of time (several hundred ms) for the future to
+            /// resolve depending on the configured SPEED if waking up from
+            /// standby mode.
+            pub async fn read(
+                &mut self,
+                delay: &mut impl embedded_hal_async::delay::DelayNs,
+            ) -> i32 {
+                self.read_calibrated(delay, ()).await.corrected
+            }
+
+            /// Performs the same operation as [`Self::read`], but returns the
+            /// frame exactly as it was clocked out of DOUT (MSB first, right
+            /// aligned, and without sign extension or any corrections)
+            pub async fn read_raw(
+                &mut self,
+                delay: &mut impl embedded_hal_async::delay::DelayNs,
+            ) -> u32 {
+                self.read_channel(delay, ()).await
+            }
+        }
+    };
 }
+
+/* ======== ADS1130 ======== */
+
+single_channel_model!(
+    /// 16-bit single-channel member of the family. It has no mux pins, so the
+    /// driver is constructed without A0/A1.
+    ADS1130,
+    16
+);
+
+/* ======== ADS1131 ======== */
+
+single_channel_model!(
+    /// 18-bit single-channel member of the family. It has no mux pins, so the
+    /// driver is constructed without A0/A1.
+    ADS1131,
+    18
+);
@@ -0,0 +1,114 @@
+//! Constructor macros that name every pin
+//!
+//! The model constructors take their pins positionally, and since most HALs
+//! give every pin its own type, swapping two of them usually still compiles.
+//! These macros take each pin by name instead, followed by any of an optional
+//! `config`, `clock`, and `hooks` (in that order), and expand to the
+//! constructor and builder calls.
+
+/// Creates an ADS1232 driver from named pins, e.g.
+/// `ads1232!(dout: p.PA0, sclk: p.PA1, pwdn: p.PA2, a0: p.PA3, temp: p.PA4)`
+///
+/// The mux pins are named after the chip's A0 and TEMP pins. Any of
+/// `config: ...`, `clock: ...`, and `hooks: ...` may follow, in that order.
+#[cfg(feature = "ads1232")]
+#[macro_export]
+macro_rules! ads1232 {
+    (
+        dout: $dout:expr,
+        sclk: $sclk:expr,
+        pwdn: $pwdn:expr,
+        a0: $a0:expr,
+        temp: $temp:expr
+        $(, config: $config:expr)?
+        $(, clock: $clock:expr)?
+        $(, hooks: $hooks:expr)?
+        $(,)?
+    ) => {{
+        let ads = $crate::ADS1232::new($dout, $sclk, $pwdn, $a0, $temp);
+        $crate::__ads123x_build!(ads $(, config: $config)? $(, clock: $clock)? $(, hooks: $hooks)?)
+    }};
+}
+
+/// Creates an ADS1234 driver from named pins, e.g.
+/// `ads1234!(dout: p.PA0, sclk: p.PA1, pwdn: p.PA2, a0: p.PA3, a1: p.PA4)`
+///
+/// Any of `config: ...`, `clock: ...`, and `hooks: ...` may follow, in that
+/// order.
+#[cfg(feature = "ads1234")]
+#[macro_export]
+macro_rules! ads1234 {
+    (
+        dout: $dout:expr,
+        sclk: $sclk:expr,
+        pwdn: $pwdn:expr,
+        a0: $a0:expr,
+        a1: $a1:expr
+        $(, config: $config:expr)?
+        $(, clock: $clock:expr)?
+        $(, hooks: $hooks:expr)?
+        $(,)?
+    ) => {{
+        let ads = $crate::ADS1234::new($dout, $sclk, $pwdn, $a0, $a1);
+        $crate::__ads123x_build!(ads $(, config: $config)? $(, clock: $clock)? $(, hooks: $hooks)?)
+    }};
+}
+
+/// Creates an ADS1130 driver from named pins, e.g.
+/// `ads1130!(dout: p.PA0, sclk: p.PA1, pwdn: p.PA2)`
+///
+/// Any of `config: ...`, `clock: ...`, and `hooks: ...` may follow, in that
+/// order.
+#[macro_export]
+macro_rules! ads1130 {
+    (
+        dout: $dout:expr,
+        sclk: $sclk:expr,
+        pwdn: $pwdn:expr
+        $(, config: $config:expr)?
+        $(, clock: $clock:expr)?
+        $(, hooks: $hooks:expr)?
+        $(,)?
+    ) => {{
+        let ads = $crate::ADS1130::new($dout, $sclk, $pwdn);
+        $crate::__ads123x_build!(ads $(, config: $config)? $(, clock: $clock)? $(, hooks: $hooks)?)
+    }};
+}
+
+/// Creates an ADS1131 driver from named pins, e.g.
+/// `ads1131!(dout: p.PA0, sclk: p.PA1, pwdn: p.PA2)`
+///
+/// Any of `config: ...`, `clock: ...`, and `hooks: ...` may follow, in that
+/// order.
+#[macro_export]
+macro_rules! ads1131 {
+    (
+        dout: $dout:expr,
+        sclk: $sclk:expr,
+        pwdn: $pwdn:expr
+        $(, config: $config:expr)?
+        $(, clock: $clock:expr)?
+        $(, hooks: $hooks:expr)?
+        $(,)?
+    ) => {{
+        let ads = $crate::ADS1131::new($dout, $sclk, $pwdn);
+        $crate::__ads123x_build!(ads $(, config: $config)? $(, clock: $clock)? $(, hooks: $hooks)?)
+    }};
+}
+
+/// Applies the optional builder calls shared by the constructor macros
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ads123x_build {
+    (
+        $ads:ident
+        $(, config: $config:expr)?
+        $(, clock: $clock:expr)?
+        $(, hooks: $hooks:expr)?
+    ) => {{
+        $(let $ads = $ads.with_config($config);)?
+        $(let $ads = $ads.with_clock($clock);)?
+        $(let $ads = $ads.with_hooks($hooks);)?
+        $ads
+    }};
+}
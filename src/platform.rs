@@ -0,0 +1,694 @@
+//! Weighing platforms with one load cell per input
+//!
+//! A [`Platform`] reads every channel of a converter (e.g. the four corners of
+//! a platform wired to an ADS1234), subtracts each cell's tare, multiplies the
+//! result by a per-cell trim factor that equalizes the cells' sensitivities,
+//! and converts the sum to a single [`Weight`] using a span calibrated for the
+//! whole platform.
+//!
+//! Switching channels restarts the conversion cycle, so every switch costs a
+//! full settling time. Scans alternate direction (e.g. 1, 2, 3, 4 then 4, 3,
+//! 2, 1) so that each one starts on the cell the mux is already set to, which
+//! saves one switch per scan over always starting from the first cell.
+//!
+//! The trims can be set by hand, or measured by a guided corner calibration:
+//! after taring, a reference mass is placed on each corner in turn and
+//! measured with [`Platform::measure_corner_blocking`], and
+//! [`Platform::apply_corner_trims`] then computes the trims under which the
+//! platform reads the same wherever the mass is placed. The trims are part of
+//! the [`Calibration`] returned by [`Platform::calibration`].
+//!
+//! A failing or mechanically binding cell shows up as a shift in how a load is
+//! distributed across the cells. An [`ImbalanceDetector`] learns each cell's
+//! share of a known load and flags a cell whose share moves outside a band
+//! around it on later readings of the same load.
+
+use core::marker::PhantomData;
+
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{InputPin, OutputPin, StatefulOutputPin},
+};
+
+use crate::{
+    calibration::Calibration,
+    scale::{check_span, Span},
+    units::{saturate, Weight},
+    ADSModel, AdsHooks, Clock, Error, TimingProfile, ADS123X, GAIN_FRACTIONAL_BITS, MAX_CHANNELS,
+    UNITY_GAIN,
+};
+
+/// Net readings of every cell with a reference mass on each corner, collected
+/// by [`Platform::measure_corner_blocking`]
+#[derive(Debug)]
+pub struct CornerCalibration<M: ADSModel> {
+    /// Net reading of every cell (columns) with the mass on each corner (rows)
+    responses: [[i64; MAX_CHANNELS]; MAX_CHANNELS],
+    measured: [bool; MAX_CHANNELS],
+    _model: PhantomData<M>,
+}
+
+impl<M: ADSModel> CornerCalibration<M> {
+    /// Creates a calibration with no corners measured yet
+    pub fn new() -> Self {
+        Self {
+            responses: [[0; MAX_CHANNELS]; MAX_CHANNELS],
+            measured: [false; MAX_CHANNELS],
+            _model: PhantomData,
+        }
+    }
+
+    /// Whether the given corner has been measured
+    pub fn is_measured(&self, corner: M::Channel) -> bool {
+        self.measured[M::channel_index(corner)]
+    }
+
+    /// Whether every corner has been measured
+    pub fn is_complete(&self) -> bool {
+        self.measured[..M::CHANNELS.len()]
+            .iter()
+            .all(|&measured| measured)
+    }
+
+    /// Computes trims (as plain factors) under which every corner reads the
+    /// same, normalized to average one, and the trimmed sum that the mass
+    /// reads as
+    fn solve(&self) -> Option<([f64; MAX_CHANNELS], f64)> {
+        let n = M::CHANNELS.len();
+
+        // Solve responses * trims = 1 by Gaussian elimination with partial
+        // pivoting, on an augmented matrix
+        let mut a = [[0f64; MAX_CHANNELS + 1]; MAX_CHANNELS];
+        let mut largest = 0f64;
+
+        for (row, responses) in a.iter_mut().zip(&self.responses).take(n) {
+            for (entry, &response) in row.iter_mut().zip(&responses[..n]) {
+                *entry = response as f64;
+                largest = largest.max(magnitude(*entry));
+            }
+            row[n] = 1.0;
+        }
+
+        for col in 0..n {
+            let pivot =
+                (col..n).max_by(|&i, &j| magnitude(a[i][col]).total_cmp(&magnitude(a[j][col])))?;
+
+            if magnitude(a[pivot][col]) <= largest * 1e-9 {
+                return None;
+            }
+
+            a.swap(col, pivot);
+
+            let pivot_row = a[col];
+
+            for (i, row) in a.iter_mut().enumerate().take(n) {
+                if i != col {
+                    let factor = row[col] / pivot_row[col];
+
+                    for (entry, &pivot_entry) in row.iter_mut().zip(&pivot_row).skip(col) {
+                        *entry -= factor * pivot_entry;
+                    }
+                }
+            }
+        }
+
+        let mut trims = [0f64; MAX_CHANNELS];
+        let mut total = 0f64;
+
+        for (i, trim) in trims.iter_mut().enumerate().take(n) {
+            *trim = a[i][n] / a[i][i];
+            total += *trim;
+        }
+
+        // Scaling every trim by the same factor scales every corner's response
+        // by it too, starting from one
+        let scale = n as f64 / total;
+
+        for trim in trims.iter_mut().take(n) {
+            *trim *= scale;
+        }
+
+        Some((trims, scale))
+    }
+}
+
+impl<M: ADSModel> Default for CornerCalibration<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn magnitude(value: f64) -> f64 {
+    if value < 0.0 {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Outcome of checking how a load is distributed across the cells, see
+/// [`ImbalanceDetector::check`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BalanceCheck<C> {
+    /// Every cell carries its expected share of the load
+    Balanced,
+    /// The load is too light (or no distribution was learned yet) for the
+    /// shares to be meaningful
+    Unloaded,
+    /// The cell whose share is furthest from its expected share, in parts per
+    /// million of the total load
+    Imbalanced {
+        cell: C,
+        share_ppm: i32,
+        expected_ppm: i32,
+    },
+}
+
+/// Flags a cell whose share of a known load drifts outside the band learned
+/// for it, e.g. because it is failing or binding
+#[derive(Debug)]
+pub struct ImbalanceDetector<M: ADSModel> {
+    expected_ppm: Option<[i32; MAX_CHANNELS]>,
+    tolerance_ppm: u32,
+    min_load: i64,
+    _model: PhantomData<M>,
+}
+
+impl<M: ADSModel> ImbalanceDetector<M> {
+    /// Creates a detector that allows each cell's share to deviate from the
+    /// learned one by `tolerance_ppm` parts per million of the total load, and
+    /// only judges loads of at least `min_load` (trimmed) counts
+    pub fn new(tolerance_ppm: u32, min_load: i64) -> Self {
+        Self {
+            expected_ppm: None,
+            tolerance_ppm,
+            min_load: min_load.max(1),
+            _model: PhantomData,
+        }
+    }
+
+    /// Takes the distribution of `cells` (see [`Platform::last_cells`]) under
+    /// a known load as the expected one
+    ///
+    /// Returns [`Error::InvalidCalibration`] if the load is lighter than the
+    /// minimum, in which case the previous distribution is kept.
+    pub fn learn(&mut self, cells: &[i64; MAX_CHANNELS]) -> Result<(), Error> {
+        self.expected_ppm = Some(self.shares(cells).ok_or(Error::InvalidCalibration)?);
+
+        Ok(())
+    }
+
+    /// Sets the expected share of every cell in parts per million, indexed like
+    /// [`ADSModel::CHANNELS`], e.g. to restore a learned distribution
+    pub fn set_expected(&mut self, shares_ppm: [i32; MAX_CHANNELS]) {
+        self.expected_ppm = Some(shares_ppm);
+    }
+
+    /// Expected share of every cell in parts per million, if one was learned
+    /// or set
+    pub fn expected(&self) -> Option<[i32; MAX_CHANNELS]> {
+        self.expected_ppm
+    }
+
+    /// Compares the distribution of `cells` (see [`Platform::last_cells`]) with
+    /// the expected one
+    pub fn check(&self, cells: &[i64; MAX_CHANNELS]) -> BalanceCheck<M::Channel> {
+        let (Some(expected), Some(shares)) = (self.expected_ppm, self.shares(cells)) else {
+            return BalanceCheck::Unloaded;
+        };
+
+        let worst = M::CHANNELS
+            .iter()
+            .zip(shares.into_iter().zip(expected))
+            .max_by_key(|(_, (share, expected))| share.abs_diff(*expected));
+
+        match worst {
+            Some((&cell, (share_ppm, expected_ppm)))
+                if share_ppm.abs_diff(expected_ppm) > self.tolerance_ppm =>
+            {
+                BalanceCheck::Imbalanced {
+                    cell,
+                    share_ppm,
+                    expected_ppm,
+                }
+            }
+            _ => BalanceCheck::Balanced,
+        }
+    }
+
+    /// Share of every cell in parts per million of the total load, or `None`
+    /// if the load is lighter than the minimum
+    fn shares(&self, cells: &[i64; MAX_CHANNELS]) -> Option<[i32; MAX_CHANNELS]> {
+        let cells = &cells[..M::CHANNELS.len()];
+        let total: i128 = cells.iter().map(|&counts| counts as i128).sum();
+
+        if total < self.min_load as i128 {
+            return None;
+        }
+
+        let mut shares = [0; MAX_CHANNELS];
+
+        for (share, &counts) in shares.iter_mut().zip(cells) {
+            *share = (counts as i128 * 1_000_000 / total).clamp(i32::MIN as i128, i32::MAX as i128)
+                as i32;
+        }
+
+        Some(shares)
+    }
+}
+
+/// Load cells connected to every channel of a converter, read as one scale
+pub struct Platform<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>,
+    tares: [i32; MAX_CHANNELS],
+    trims: [i32; MAX_CHANNELS],
+    span: Span,
+    span_counts: i32,
+    reverse: bool,
+    last_cells: [i64; MAX_CHANNELS],
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> Platform<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Creates a platform over every channel of the model, with no tare, unity
+    /// trims, and a span of one milligram per count until it is calibrated
+    pub fn new(ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>) -> Self {
+        Self {
+            ads,
+            tares: [0; MAX_CHANNELS],
+            trims: [UNITY_GAIN; MAX_CHANNELS],
+            span: Span::Weight(Weight::from_milligrams(1)),
+            span_counts: 1,
+            reverse: false,
+            last_cells: [0; MAX_CHANNELS],
+        }
+    }
+
+    /// Releases the driver
+    pub fn free(self) -> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> {
+        self.ads
+    }
+
+    /// Returns the driver, e.g. to change its configuration
+    pub fn ads_mut(&mut self) -> &mut ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> {
+        &mut self.ads
+    }
+
+    /// Returns the reading (in corrected counts) of the given cell that
+    /// corresponds to an empty platform
+    pub fn tare_counts(&self, channel: M::Channel) -> i32 {
+        self.tares[M::channel_index(channel)]
+    }
+
+    /// Sets the reading (in corrected counts) of the given cell that
+    /// corresponds to an empty platform, e.g. to restore one that was
+    /// previously measured and persisted
+    pub fn set_tare_counts(&mut self, channel: M::Channel, tare: i32) {
+        self.tares[M::channel_index(channel)] = tare;
+    }
+
+    /// Returns the factor (as Q8.24 fixed point, see [`UNITY_GAIN`]) the net
+    /// reading of the given cell is multiplied by before it is summed
+    pub fn trim(&self, channel: M::Channel) -> i32 {
+        self.trims[M::channel_index(channel)]
+    }
+
+    /// Sets the factor (as Q8.24 fixed point, see [`UNITY_GAIN`]) the net
+    /// reading of the given cell is multiplied by before it is summed
+    ///
+    /// The span is calibrated against the trimmed sum, so changing a trim
+    /// invalidates it.
+    pub fn set_trim(&mut self, channel: M::Channel, trim: i32) {
+        self.trims[M::channel_index(channel)] = trim;
+    }
+
+    /// Returns the span's reference and the number of counts (the trimmed sum
+    /// above the tares) that it reads as
+    pub fn span(&self) -> (Span, i32) {
+        (self.span, self.span_counts)
+    }
+
+    /// Sets the span as a reference weight or force and the number of counts
+    /// (the trimmed sum above the tares) that it reads as, e.g. to restore one
+    /// that was previously calibrated and persisted
+    ///
+    /// Returns the same errors as [`crate::scale::Scale::set_span`].
+    pub fn set_span(&mut self, reference: impl Into<Span>, counts: i32) -> Result<(), Error> {
+        let reference = reference.into();
+        check_span(reference, counts)?;
+
+        self.span = reference;
+        self.span_counts = counts;
+
+        Ok(())
+    }
+
+    /// Returns the driver's calibration together with the trims of every cell
+    pub fn calibration(&self) -> Calibration {
+        Calibration {
+            trims: self.trims,
+            ..self.ads.calibration()
+        }
+    }
+
+    /// Replaces the driver's calibration and the trims of every cell, e.g.
+    /// with ones restored from non-volatile memory
+    pub fn set_calibration(&mut self, calibration: &Calibration) {
+        self.ads.set_calibration(calibration);
+        self.trims = calibration.trims;
+    }
+
+    /// Net reading of the given cell above its tare, multiplied by its trim
+    pub fn trimmed_counts(&self, channel: M::Channel, counts: i32) -> i64 {
+        let index = M::channel_index(channel);
+        let net = counts as i64 - self.tares[index] as i64;
+
+        net.saturating_mul(self.trims[index] as i64) >> GAIN_FRACTIONAL_BITS
+    }
+
+    /// Trimmed net reading of every cell, indexed like [`ADSModel::CHANNELS`],
+    /// from the last call to [`Self::read_counts_blocking`] (or
+    /// [`Self::read_weight_blocking`])
+    pub fn last_cells(&self) -> [i64; MAX_CHANNELS] {
+        self.last_cells
+    }
+
+    /// Converts a trimmed sum (see [`Self::read_counts_blocking`]) to a weight
+    /// using the span, converting a force span using standard gravity
+    pub fn counts_to_weight(&self, counts: i64) -> Weight {
+        let milligrams = (counts as i128).saturating_mul(self.span.weight().milligrams() as i128)
+            / self.span_counts as i128;
+
+        Weight::from_milligrams(saturate(milligrams))
+    }
+
+    /// Reads every cell once and returns the sum of their trimmed net
+    /// readings (see [`Self::trimmed_counts`])
+    pub fn read_counts_blocking(&mut self, delay: &mut impl DelayNs) -> i64 {
+        let cells = self.read_cells_blocking(delay);
+
+        self.record_scan(&cells)
+    }
+
+    /// Reads every cell once and returns the total weight on the platform
+    pub fn read_weight_blocking(&mut self, delay: &mut impl DelayNs) -> Weight {
+        let counts = self.read_counts_blocking(delay);
+
+        self.counts_to_weight(counts)
+    }
+
+    /// Averages `n` scans (at least one) of the empty platform and stores the
+    /// result of every cell as its tare
+    pub fn tare_blocking(&mut self, delay: &mut impl DelayNs, n: u32) {
+        let mut totals = [0i64; MAX_CHANNELS];
+
+        for _ in 0..n.max(1) {
+            let cells = self.read_cells_blocking(delay);
+            accumulate(&mut totals, &cells);
+        }
+
+        for (tare, total) in self.tares.iter_mut().zip(totals) {
+            *tare = (total / n.max(1) as i64) as i32;
+        }
+    }
+
+    /// Averages `n` scans (at least one) with the reference mass on the given
+    /// corner of the (previously tared) platform and records the net reading
+    /// of every cell in `calibration`
+    ///
+    /// Measuring a corner again replaces its previous measurement.
+    pub fn measure_corner_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        calibration: &mut CornerCalibration<M>,
+        corner: M::Channel,
+        n: u32,
+    ) {
+        let mut totals = [0i64; MAX_CHANNELS];
+
+        for _ in 0..n.max(1) {
+            let cells = self.read_cells_blocking(delay);
+            accumulate(&mut totals, &cells);
+        }
+
+        self.record_corner(calibration, corner, totals, n);
+    }
+
+    /// Computes the trims under which the platform reads the same wherever the
+    /// reference mass of `calibration` is placed, and applies them along with a
+    /// span for the mass (which weighs `reference`)
+    ///
+    /// The trims average to [`UNITY_GAIN`]. Returns
+    /// [`Error::InvalidCalibration`] if a corner hasn't been measured, the
+    /// measurements don't determine the trims (e.g. the same corner was loaded
+    /// every time), or a trim wouldn't be a positive Q8.24 factor, and the
+    /// errors of [`Self::set_span`]. Nothing is changed on error.
+    pub fn apply_corner_trims(
+        &mut self,
+        calibration: &CornerCalibration<M>,
+        reference: impl Into<Span>,
+    ) -> Result<[i32; MAX_CHANNELS], Error> {
+        if !calibration.is_complete() {
+            return Err(Error::InvalidCalibration);
+        }
+
+        let (factors, response) = calibration.solve().ok_or(Error::InvalidCalibration)?;
+        let mut trims = [UNITY_GAIN; MAX_CHANNELS];
+
+        for (trim, factor) in trims.iter_mut().zip(factors).take(M::CHANNELS.len()) {
+            let fixed = factor * UNITY_GAIN as f64 + 0.5;
+
+            if !(1.0..i32::MAX as f64).contains(&fixed) {
+                return Err(Error::InvalidCalibration);
+            }
+
+            *trim = fixed as i32;
+        }
+
+        let counts = response + 0.5;
+
+        if !(i32::MIN as f64..=i32::MAX as f64).contains(&counts) {
+            return Err(Error::Overflow);
+        }
+
+        self.set_span(reference, counts as i32)?;
+        self.trims = trims;
+
+        Ok(trims)
+    }
+
+    /// Averages `n` scans (at least one) with a known reference weight or
+    /// force on the (previously tared) platform and stores the resulting
+    /// span
+    ///
+    /// Returns the errors of [`Self::set_span`], in which case the previous
+    /// span is kept.
+    pub fn calibrate_span_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        reference: impl Into<Span>,
+        n: u32,
+    ) -> Result<(), Error> {
+        let mut total = 0i64;
+
+        for _ in 0..n.max(1) {
+            total += self.read_counts_blocking(delay);
+        }
+
+        let counts = (total / n.max(1) as i64).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+
+        self.set_span(reference, counts)
+    }
+
+    /// Stores the average net reading of every cell from the `totals` of `n`
+    /// scans as the response to the given corner
+    fn record_corner(
+        &self,
+        calibration: &mut CornerCalibration<M>,
+        corner: M::Channel,
+        totals: [i64; MAX_CHANNELS],
+        n: u32,
+    ) {
+        let index = M::channel_index(corner);
+
+        for ((response, total), tare) in calibration.responses[index]
+            .iter_mut()
+            .zip(totals)
+            .zip(self.tares)
+        {
+            *response = total / n.max(1) as i64 - tare as i64;
+        }
+
+        calibration.measured[index] = true;
+    }
+
+    /// Reads the corrected value of every cell, indexed like
+    /// [`ADSModel::CHANNELS`], in the direction of the current scan
+    fn read_cells_blocking(&mut self, delay: &mut impl DelayNs) -> [i32; MAX_CHANNELS] {
+        let mut cells = [0; MAX_CHANNELS];
+
+        for index in self.scan_order() {
+            let channel = M::CHANNELS[index];
+            cells[index] = self.ads.read_calibrated_blocking(delay, channel).corrected;
+        }
+
+        cells
+    }
+
+    /// Returns the indices of the channels in the order the next scan reads
+    /// them, and flips the direction for the scan after it
+    fn scan_order(&mut self) -> impl Iterator<Item = usize> {
+        let reverse = self.reverse;
+        self.reverse = !reverse;
+        let len = M::CHANNELS.len();
+
+        (0..len).map(move |i| if reverse { len - 1 - i } else { i })
+    }
+
+    /// Stores the trimmed net readings of every cell and returns their sum
+    fn record_scan(&mut self, cells: &[i32; MAX_CHANNELS]) -> i64 {
+        for (index, (&channel, &counts)) in M::CHANNELS.iter().zip(cells).enumerate() {
+            self.last_cells[index] = self.trimmed_counts(channel, counts);
+        }
+
+        self.last_cells
+            .iter()
+            .fold(0i64, |sum, &counts| sum.saturating_add(counts))
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> Platform<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin + embedded_hal_async::digital::Wait,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Reads every cell once asynchronously and returns the sum of their
+    /// trimmed net readings
+    pub async fn read_counts(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> i64 {
+        let cells = self.read_cells(delay).await;
+
+        self.record_scan(&cells)
+    }
+
+    /// Reads every cell once asynchronously and returns the total weight on
+    /// the platform
+    pub async fn read_weight(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> Weight {
+        let counts = self.read_counts(delay).await;
+
+        self.counts_to_weight(counts)
+    }
+
+    /// Averages `n` asynchronous scans (at least one) of the empty platform
+    /// and stores the result of every cell as its tare
+    pub async fn tare(&mut self, delay: &mut impl embedded_hal_async::delay::DelayNs, n: u32) {
+        let mut totals = [0i64; MAX_CHANNELS];
+
+        for _ in 0..n.max(1) {
+            let cells = self.read_cells(delay).await;
+            accumulate(&mut totals, &cells);
+        }
+
+        for (tare, total) in self.tares.iter_mut().zip(totals) {
+            *tare = (total / n.max(1) as i64) as i32;
+        }
+    }
+
+    /// Averages `n` asynchronous scans (at least one) with the reference mass
+    /// on the given corner and records the net reading of every cell
+    ///
+    /// See [`Self::measure_corner_blocking`] for details.
+    pub async fn measure_corner(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        calibration: &mut CornerCalibration<M>,
+        corner: M::Channel,
+        n: u32,
+    ) {
+        let mut totals = [0i64; MAX_CHANNELS];
+
+        for _ in 0..n.max(1) {
+            let cells = self.read_cells(delay).await;
+            accumulate(&mut totals, &cells);
+        }
+
+        self.record_corner(calibration, corner, totals, n);
+    }
+
+    /// Averages `n` asynchronous scans (at least one) with a known reference
+    /// weight or force on the platform and stores the resulting span
+    ///
+    /// See [`Self::calibrate_span_blocking`] for details.
+    pub async fn calibrate_span(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        reference: impl Into<Span>,
+        n: u32,
+    ) -> Result<(), Error> {
+        let mut total = 0i64;
+
+        for _ in 0..n.max(1) {
+            total += self.read_counts(delay).await;
+        }
+
+        let counts = (total / n.max(1) as i64).clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+
+        self.set_span(reference, counts)
+    }
+
+    async fn read_cells(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> [i32; MAX_CHANNELS] {
+        let mut cells = [0; MAX_CHANNELS];
+
+        for index in self.scan_order() {
+            let channel = M::CHANNELS[index];
+            cells[index] = self.ads.read_calibrated(delay, channel).await.corrected;
+        }
+
+        cells
+    }
+}
+
+/// Adds the readings of one scan to the running totals of every cell
+fn accumulate(totals: &mut [i64; MAX_CHANNELS], cells: &[i32; MAX_CHANNELS]) {
+    for (total, &cell) in totals.iter_mut().zip(cells) {
+        *total += cell as i64;
+    }
+}
@@ -0,0 +1,95 @@
+//! Async support for DOUT pins that can't wait for edges
+//!
+//! The async API requires DOUT to implement
+//! [`Wait`](embedded_hal_async::digital::Wait), which usually means routing it
+//! to an external interrupt (EXTI) line. On boards where that isn't possible,
+//! wrap the pin in a [`PollingPin`], which implements `Wait` by periodically
+//! sampling the pin and sleeping on an async delay in between.
+
+use embedded_hal::digital::{ErrorType, InputPin};
+use embedded_hal_async::{delay::DelayNs, digital::Wait};
+
+/// An input pin that implements [`Wait`] by polling
+pub struct PollingPin<P, D> {
+    pin: P,
+    delay: D,
+    interval_us: u32,
+}
+
+impl<P: InputPin, D: DelayNs> PollingPin<P, D> {
+    /// Wraps a pin that will be sampled every `interval_us` microseconds while
+    /// waiting, using its own `delay` to sleep in between
+    ///
+    /// The interval adds up to that much latency to every read, so something
+    /// around 1% of the conversion period (e.g. 1000 us at 10 SPS or 100 us at
+    /// 80 SPS) is a reasonable trade-off with CPU usage.
+    pub fn new(pin: P, delay: D, interval_us: u32) -> Self {
+        Self {
+            pin,
+            delay,
+            interval_us,
+        }
+    }
+
+    /// Wraps a pin that will be sampled every `interval` while waiting, see
+    /// [`Self::new`]
+    #[cfg(feature = "fugit")]
+    pub fn with_interval(pin: P, delay: D, interval: fugit::MicrosDurationU32) -> Self {
+        Self::new(pin, delay, interval.ticks())
+    }
+
+    /// Releases the pin and delay
+    pub fn free(self) -> (P, D) {
+        (self.pin, self.delay)
+    }
+
+    async fn wait_for_state(&mut self, high: bool) -> Result<(), P::Error> {
+        while self.pin.is_high()? != high {
+            self.delay.delay_us(self.interval_us).await;
+        }
+
+        Ok(())
+    }
+}
+
+impl<P: InputPin, D> ErrorType for PollingPin<P, D> {
+    type Error = P::Error;
+}
+
+impl<P: InputPin, D> InputPin for PollingPin<P, D> {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        self.pin.is_high()
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        self.pin.is_low()
+    }
+}
+
+impl<P: InputPin, D: DelayNs> Wait for PollingPin<P, D> {
+    async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_state(true).await
+    }
+
+    async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_state(false).await
+    }
+
+    /// Edges shorter than the polling interval can be missed
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_state(false).await?;
+        self.wait_for_state(true).await
+    }
+
+    /// Edges shorter than the polling interval can be missed
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_state(true).await?;
+        self.wait_for_state(false).await
+    }
+
+    /// Edges shorter than the polling interval can be missed
+    async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+        let high = self.pin.is_high()?;
+        self.wait_for_state(!high).await
+    }
+}
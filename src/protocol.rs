@@ -0,0 +1,224 @@
+//! Sans-I/O implementation of the serial interface
+//!
+//! A [`FrameReader`] decides what has to happen on SCLK and DOUT to shift a
+//! conversion out of the chip, but never touches the pins itself. Instead it
+//! hands out one [`Action`] at a time for the caller to carry out. The blocking
+//! and async APIs of the driver are thin shells around it, so the two can't
+//! drift apart, and applications that want to clock frames out from an
+//! interrupt handler (e.g. performing one action per timer interrupt) can
+//! drive it the same way.
+
+use embedded_hal::digital::PinState;
+
+/// A step of shifting out a frame, to be carried out by the caller
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Action {
+    /// Set SCLK to the given level
+    SetSclk(PinState),
+    /// Wait for at least the given number of nanoseconds, which is never zero
+    Delay(u32),
+    /// Wait for DOUT to go high, which it briefly does before the next
+    /// conversion is ready if the previous frame wasn't followed by an extra
+    /// SCLK pulse
+    WaitForUpdate,
+    /// Wait for DOUT to go low, which signals that a conversion is ready
+    WaitForDrdy,
+    /// Sample DOUT and pass its level to [`FrameReader::sampled`]
+    SampleDout,
+    /// A data bit (with index 0 being the MSB) has been sampled and SCLK is
+    /// low again, so any per-bit bookkeeping won't stretch the high pulse
+    BitRead { index: u32, level: PinState },
+    /// The frame has been shifted out completely, with the data right aligned
+    /// and without sign extension
+    ///
+    /// Every further call to [`FrameReader::next_action`] returns this again.
+    Done(u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+enum State {
+    Start,
+    /// Step within the SCLK pulse that finishes off the previous frame
+    LeadingPulse {
+        step: u8,
+    },
+    WaitForUpdate,
+    WaitForDrdy,
+    /// Step within the SCLK pulse with the given index
+    Pulse {
+        index: u32,
+        step: u8,
+    },
+    Done,
+}
+
+/// State machine that shifts a single frame out of the chip
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FrameReader {
+    bits: u32,
+    pulses: u32,
+    high_ns: u32,
+    low_ns: u32,
+    leading_pulse: bool,
+    wait_for_update: bool,
+    state: State,
+    data: u32,
+    level: bool,
+}
+
+impl FrameReader {
+    /// Creates a reader for a frame of `bits` data bits followed by SCLK
+    /// pulses up to a total of `pulses` (at least `bits`), with SCLK held high
+    /// for `high_ns` and low for `low_ns` during each pulse
+    ///
+    /// Regular reads pulse SCLK once more than the data width to force DRDY
+    /// high (unless configured otherwise through [`crate::TrailingPulse`]),
+    /// and offset calibrations twice more.
+    pub const fn new(bits: u32, pulses: u32, high_ns: u32, low_ns: u32) -> Self {
+        Self {
+            bits,
+            pulses: if pulses < bits { bits } else { pulses },
+            high_ns,
+            low_ns,
+            leading_pulse: false,
+            wait_for_update: false,
+            state: State::Start,
+            data: 0,
+            level: false,
+        }
+    }
+
+    /// Pulses SCLK once before waiting for the conversion, to finish off a
+    /// previous frame that wasn't followed by an extra pulse
+    pub const fn with_leading_pulse(mut self) -> Self {
+        self.leading_pulse = true;
+        self
+    }
+
+    /// Waits for DOUT to go high before waiting for the conversion, so that a
+    /// previous frame that wasn't followed by an extra pulse (and may have left
+    /// DOUT low) isn't mistaken for a new conversion
+    pub const fn with_wait_for_update(mut self) -> Self {
+        self.wait_for_update = true;
+        self
+    }
+
+    /// Returns the next step to carry out
+    pub fn next_action(&mut self) -> Action {
+        loop {
+            let (next, action) = self.step();
+            self.state = next;
+
+            // Zero length delays are skipped rather than handed out
+            if action != Action::Delay(0) {
+                return action;
+            }
+        }
+    }
+
+    /// Records the level of DOUT after an [`Action::SampleDout`]
+    pub fn sampled(&mut self, high: bool) {
+        // Data is shifted out MSB first
+        self.data = (self.data << 1) | high as u32;
+        self.level = high;
+    }
+
+    /// Returns the state following the current one and the action for it
+    fn step(&self) -> (State, Action) {
+        match self.state {
+            State::Start => {
+                let next = if self.leading_pulse {
+                    State::LeadingPulse { step: 0 }
+                } else {
+                    self.after_leading_pulse()
+                };
+
+                (next, Action::SetSclk(PinState::Low))
+            }
+            State::LeadingPulse { step } => {
+                let next = match step {
+                    3 => self.after_leading_pulse(),
+                    _ => State::LeadingPulse { step: step + 1 },
+                };
+
+                let action = match step {
+                    0 => Action::SetSclk(PinState::High),
+                    1 => Action::Delay(self.high_ns),
+                    2 => Action::SetSclk(PinState::Low),
+                    _ => Action::Delay(self.low_ns),
+                };
+
+                (next, action)
+            }
+            State::WaitForUpdate => (State::WaitForDrdy, Action::WaitForUpdate),
+            State::WaitForDrdy => (State::Pulse { index: 0, step: 0 }, Action::WaitForDrdy),
+            State::Pulse { index, .. } if index >= self.pulses => {
+                (State::Done, Action::Done(self.data))
+            }
+            State::Pulse { index, step } if index < self.bits => {
+                let next = match step {
+                    6 => State::Pulse {
+                        index: index + 1,
+                        step: 0,
+                    },
+                    _ => State::Pulse {
+                        index,
+                        step: step + 1,
+                    },
+                };
+
+                // DOUT is sampled half way through the high time
+                let action = match step {
+                    0 => Action::SetSclk(PinState::High),
+                    1 => Action::Delay(self.high_ns / 2),
+                    2 => Action::SampleDout,
+                    3 => Action::Delay(self.high_ns - self.high_ns / 2),
+                    4 => Action::SetSclk(PinState::Low),
+                    5 => Action::BitRead {
+                        index,
+                        level: PinState::from(self.level),
+                    },
+                    _ => Action::Delay(self.low_ns),
+                };
+
+                (next, action)
+            }
+            // Pulses past the data bits only serve to signal the chip
+            State::Pulse { index, step } => {
+                let next = match step {
+                    3 => State::Pulse {
+                        index: index + 1,
+                        step: 0,
+                    },
+                    _ => State::Pulse {
+                        index,
+                        step: step + 1,
+                    },
+                };
+
+                let action = match step {
+                    0 => Action::SetSclk(PinState::High),
+                    1 => Action::Delay(self.high_ns),
+                    2 => Action::SetSclk(PinState::Low),
+                    _ => Action::Delay(self.low_ns),
+                };
+
+                (next, action)
+            }
+            State::Done => (State::Done, Action::Done(self.data)),
+        }
+    }
+
+    /// Returns the state following the leading pulse (or the start if there is
+    /// none)
+    fn after_leading_pulse(&self) -> State {
+        if self.wait_for_update {
+            State::WaitForUpdate
+        } else {
+            State::WaitForDrdy
+        }
+    }
+}
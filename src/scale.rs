@@ -0,0 +1,746 @@
+//! Weighing with a load cell
+//!
+//! A [`Scale`] wraps a driver and one of its channels, and converts readings
+//! to a [`Weight`] using a tare (the reading with nothing on the scale) and a
+//! span (the change in reading caused by a known weight). The channel's
+//! software offset, system gain, and linearity corrections are applied by the
+//! driver before the tare and span, so a [`Scale`] can be combined with a
+//! linearity correction measured for the load cell.
+//!
+//! Force rigs can calibrate the span against a known [`Force`] instead, and
+//! read back forces with [`Scale::read_force_blocking`]. Either kind of reading
+//! can be taken with either kind of span, as weights and forces are converted
+//! into each other using standard gravity.
+//!
+//! Given a [`Division`], a scale also tracks whether its most recent reading
+//! is at the center of zero, which weighing displays indicate with the →0←
+//! annunciator. Readings can also be clamped to limits (see
+//! [`Scale::set_limits`]), so that a fault that takes the converter to full
+//! scale doesn't hand a control loop a wildly out of range weight.
+
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{InputPin, OutputPin, StatefulOutputPin},
+};
+
+use crate::{
+    division::Division,
+    units::{saturate, BridgeOutput, Force, Weight, STANDARD_GRAVITY_UM_PER_S2},
+    ADSModel, AdsHooks, Clock, Error, PowerState, TimingProfile, ADS123X,
+};
+
+/// The reference a [`Scale`]'s span was calibrated against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Span {
+    Weight(Weight),
+    Force(Force),
+}
+
+impl Span {
+    /// Returns the reference as a weight, converting a force using standard
+    /// gravity
+    pub const fn weight(self) -> Weight {
+        match self {
+            Self::Weight(weight) => weight,
+            Self::Force(force) => force.to_weight(),
+        }
+    }
+
+    /// Returns the reference as a force, converting a weight using standard
+    /// gravity
+    pub const fn force(self) -> Force {
+        match self {
+            Self::Weight(weight) => weight.to_force(),
+            Self::Force(force) => force,
+        }
+    }
+}
+
+impl From<Weight> for Span {
+    fn from(weight: Weight) -> Self {
+        Self::Weight(weight)
+    }
+}
+
+impl From<Force> for Span {
+    fn from(force: Force) -> Self {
+        Self::Force(force)
+    }
+}
+
+/// How the zero reading at startup compares to a restored tare, see
+/// [`Scale::restore_blocking`]
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ZeroCheck {
+    /// The scale reads within the allowed band around zero
+    Ok { drift: Weight },
+    /// The scale reads further from zero than allowed, because either the
+    /// zero has drifted or something was left on the scale
+    Drifted { drift: Weight },
+}
+
+impl ZeroCheck {
+    /// Weight the scale read with the restored tare and span applied
+    pub const fn drift(self) -> Weight {
+        match self {
+            Self::Ok { drift } | Self::Drifted { drift } => drift,
+        }
+    }
+
+    pub const fn is_ok(self) -> bool {
+        matches!(self, Self::Ok { .. })
+    }
+}
+
+/// Checks that a span converts every difference between two readings to a
+/// weight and force that fits into an i64
+pub(crate) fn check_span(reference: Span, counts: i32) -> Result<(), Error> {
+    if counts == 0 {
+        return Err(Error::InvalidCalibration);
+    }
+
+    // The largest difference between a reading and the tare
+    let max_net = u32::MAX as i128;
+    let scale =
+        |quantity: i64| max_net * quantity.unsigned_abs() as i128 / counts.unsigned_abs() as i128;
+
+    let weight = scale(reference.weight().milligrams());
+    let force = scale(reference.force().millinewtons());
+
+    if weight.max(force) > i64::MAX as i128 {
+        return Err(Error::Overflow);
+    }
+
+    Ok(())
+}
+
+/// A load cell connected to one channel of a converter
+pub struct Scale<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>,
+    channel: M::Channel,
+    tare: i32,
+    span: Span,
+    span_counts: i32,
+    division: Option<Division>,
+    last_counts: Option<i64>,
+    limits: Option<(Weight, Weight)>,
+    clamped: bool,
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> Scale<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Creates a scale reading from the given channel, with no tare and a span
+    /// of one milligram per count until it is calibrated
+    pub fn new(ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>, channel: M::Channel) -> Self {
+        Self {
+            ads,
+            channel,
+            tare: 0,
+            span: Span::Weight(Weight::from_milligrams(1)),
+            span_counts: 1,
+            division: None,
+            last_counts: None,
+            limits: None,
+            clamped: false,
+        }
+    }
+
+    /// Releases the driver
+    pub fn free(self) -> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> {
+        self.ads
+    }
+
+    /// Returns the driver, e.g. to change its configuration
+    pub fn ads_mut(&mut self) -> &mut ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> {
+        &mut self.ads
+    }
+
+    pub fn channel(&self) -> M::Channel {
+        self.channel
+    }
+
+    /// Returns the reading (in corrected counts) that corresponds to zero
+    /// weight
+    pub fn tare_counts(&self) -> i32 {
+        self.tare
+    }
+
+    /// Sets the reading (in corrected counts) that corresponds to zero weight,
+    /// e.g. to restore one that was previously measured and persisted
+    pub fn set_tare_counts(&mut self, tare: i32) {
+        self.tare = tare;
+    }
+
+    /// Returns the span's reference and the number of counts (above the tare)
+    /// that it reads as
+    pub fn span(&self) -> (Span, i32) {
+        (self.span, self.span_counts)
+    }
+
+    /// Sets the span as a reference weight or force and the number of counts
+    /// (above the tare) that it reads as, e.g. to restore one that was
+    /// previously calibrated and persisted
+    ///
+    /// Returns [`Error::InvalidCalibration`] if `counts` is zero, or
+    /// [`Error::Overflow`] if the span is so steep that some readings would
+    /// convert to weights or forces beyond the range of their type.
+    pub fn set_span(&mut self, reference: impl Into<Span>, counts: i32) -> Result<(), Error> {
+        let reference = reference.into();
+        check_span(reference, counts)?;
+
+        self.span = reference;
+        self.span_counts = counts;
+
+        Ok(())
+    }
+
+    /// Returns the divisions the scale indicates in, if set
+    pub fn division(&self) -> Option<Division> {
+        self.division
+    }
+
+    /// Sets the divisions the scale indicates in, which
+    /// [`Self::is_center_of_zero`] needs
+    pub fn set_division(&mut self, division: Option<Division>) {
+        self.division = division;
+    }
+
+    /// Returns whether the most recent reading (or tare) is within a quarter
+    /// of the verification division of zero, i.e. whether to light the center
+    /// of zero annunciator (→0←)
+    ///
+    /// The reading is converted with the current tare and span, so this
+    /// follows a new tare right away. It is always false without a division or
+    /// before the first reading.
+    pub fn is_center_of_zero(&self) -> bool {
+        match (self.division, self.last_counts) {
+            (Some(division), Some(counts)) => division.is_center_of_zero(Weight::from_milligrams(
+                self.counts_to_micrograms(counts) / 1_000,
+            )),
+            _ => false,
+        }
+    }
+
+    /// Returns the lowest and highest weight readings are clamped to, if set
+    pub fn limits(&self) -> Option<(Weight, Weight)> {
+        self.limits
+    }
+
+    /// Clamps every reading to between two weights (in either order), or stops
+    /// clamping with `None`
+    ///
+    /// Forces are clamped to the forces the limits exert under standard
+    /// gravity. [`Self::is_clamped`] tells whether the most recent reading was
+    /// clamped, while the `counts_to_*` conversions are never clamped.
+    pub fn set_limits(&mut self, limits: Option<(Weight, Weight)>) {
+        self.limits = limits.map(|(a, b)| (a.min(b), a.max(b)));
+    }
+
+    /// Returns whether the most recent reading was outside of the limits set
+    /// with [`Self::set_limits`] and was clamped to them
+    pub fn is_clamped(&self) -> bool {
+        self.clamped
+    }
+
+    /// Clamps a weight reading to the limits, noting whether it had to be
+    fn clamp_weight(&mut self, weight: Weight) -> Weight {
+        let clamped = match self.limits {
+            Some((min, max)) => weight.clamp(min, max),
+            None => weight,
+        };
+
+        self.clamped = clamped != weight;
+        clamped
+    }
+
+    /// Clamps a reading in micrograms to the limits, noting whether it had to
+    /// be
+    fn clamp_micrograms(&mut self, micrograms: i64) -> i64 {
+        let clamped = match self.limits {
+            Some((min, max)) => micrograms.clamp(
+                min.milligrams().saturating_mul(1_000),
+                max.milligrams().saturating_mul(1_000),
+            ),
+            None => micrograms,
+        };
+
+        self.clamped = clamped != micrograms;
+        clamped
+    }
+
+    /// Clamps a force reading to the limits, noting whether it had to be
+    fn clamp_force(&mut self, force: Force) -> Force {
+        let clamped = match self.limits {
+            Some((min, max)) => force.clamp(min.to_force(), max.to_force()),
+            None => force,
+        };
+
+        self.clamped = clamped != force;
+        clamped
+    }
+
+    /// Sets the span from a load cell's datasheet instead of a calibration
+    /// weight, given its rated output at its rated capacity and the bridge
+    /// excitation in microvolts (see [`ADS123X::bridge_output_to_counts`])
+    ///
+    /// This is only as accurate as the rating, which is typically within a
+    /// fraction of a percent. Returns [`Error::InvalidCalibration`] if the
+    /// rated output is too small to register at the configured gain.
+    pub fn set_rated_span(
+        &mut self,
+        rated_output: BridgeOutput,
+        capacity: impl Into<Span>,
+        excitation_uv: u32,
+    ) -> Result<(), Error> {
+        let counts = self
+            .ads
+            .bridge_output_to_counts(rated_output, excitation_uv);
+
+        self.set_span(capacity, counts)
+    }
+
+    /// Converts a corrected reading to a weight using the tare and span
+    pub fn counts_to_weight(&self, counts: i32) -> Weight {
+        let net = counts as i128 - self.tare as i128;
+
+        match self.span {
+            Span::Weight(weight) => Weight::from_milligrams(
+                (net * weight.milligrams() as i128 / self.span_counts as i128) as i64,
+            ),
+            Span::Force(_) => self.counts_to_force(counts).to_weight(),
+        }
+    }
+
+    /// Converts a (wide) corrected reading to a weight in micrograms using the
+    /// tare and span, saturating at the bounds of an i64
+    ///
+    /// This keeps the resolution of spans finer than a milligram per count,
+    /// which [`Self::counts_to_weight`] truncates away.
+    pub fn counts_to_micrograms(&self, counts: i64) -> i64 {
+        let net = counts as i128 - self.tare as i128;
+        let span_counts = self.span_counts as i128;
+
+        let micrograms = match self.span {
+            Span::Weight(weight) => {
+                net.saturating_mul(weight.milligrams() as i128 * 1_000) / span_counts
+            }
+            // Weight is force divided by standard gravity
+            Span::Force(force) => {
+                net.saturating_mul(force.millinewtons() as i128 * 1_000_000_000_000)
+                    / (span_counts * STANDARD_GRAVITY_UM_PER_S2)
+            }
+        };
+
+        saturate(micrograms)
+    }
+
+    /// Converts a corrected reading to a force using the tare and span
+    pub fn counts_to_force(&self, counts: i32) -> Force {
+        let net = counts as i128 - self.tare as i128;
+
+        match self.span {
+            Span::Weight(_) => self.counts_to_weight(counts).to_force(),
+            Span::Force(force) => Force::from_millinewtons(
+                (net * force.millinewtons() as i128 / self.span_counts as i128) as i64,
+            ),
+        }
+    }
+
+    /// Averages `n` reads (at least one) with the scale empty and stores the
+    /// result as the tare, which is returned so that it can be persisted
+    ///
+    /// If `max_variance` is given the tare is rejected with [`Error::Unstable`]
+    /// when the reads vary by more than that (see
+    /// [`ADS123X::read_averaged_blocking`]), and the previous tare is kept.
+    pub fn tare_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        n: u32,
+        max_variance: Option<u64>,
+    ) -> Result<i32, Error> {
+        let tare = self
+            .ads
+            .read_averaged_blocking(delay, self.channel, n, max_variance)?;
+        self.tare = tare;
+        self.last_counts = Some(tare as i64);
+
+        Ok(tare)
+    }
+
+    /// Restores a persisted tare and span (as returned by
+    /// [`Self::tare_counts`] and [`Self::span`]), and checks them against the
+    /// average of `n` reads (at least one) of the empty scale
+    ///
+    /// The calibration is applied either way, but a scale that reads more than
+    /// `max_zero_drift` away from zero is reported as [`ZeroCheck::Drifted`] so
+    /// that the application can ask for a new tare rather than silently
+    /// weighing against a stale zero. Returns the errors of [`Self::set_span`]
+    /// for an invalid span, or [`Error::Unstable`] if the reads vary by more
+    /// than `max_variance`, in which case nothing is restored.
+    pub fn restore_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        tare: i32,
+        span: (Span, i32),
+        max_zero_drift: Weight,
+        n: u32,
+        max_variance: Option<u64>,
+    ) -> Result<ZeroCheck, Error> {
+        check_span(span.0, span.1)?;
+
+        let counts = self
+            .ads
+            .read_averaged_blocking(delay, self.channel, n, max_variance)?;
+
+        Ok(self.apply_restored(tare, span, max_zero_drift, counts))
+    }
+
+    /// Stores a restored tare and span, and checks the reading of the empty
+    /// scale against them
+    fn apply_restored(
+        &mut self,
+        tare: i32,
+        (reference, span_counts): (Span, i32),
+        max_zero_drift: Weight,
+        counts: i32,
+    ) -> ZeroCheck {
+        self.tare = tare;
+        self.span = reference;
+        self.span_counts = span_counts;
+        self.last_counts = Some(counts as i64);
+
+        let drift = self.counts_to_weight(counts);
+
+        if drift.abs() <= max_zero_drift.abs() {
+            ZeroCheck::Ok { drift }
+        } else {
+            ZeroCheck::Drifted { drift }
+        }
+    }
+
+    /// Averages `n` reads (at least one) with a known reference weight or force
+    /// on the (previously tared) scale and stores the resulting
+    /// span
+    ///
+    /// Returns [`Error::InvalidCalibration`] if the reference doesn't change the
+    /// reading, or [`Error::Unstable`] if the reads vary by more than
+    /// `max_variance`. The previous span is kept on error.
+    pub fn calibrate_span_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        reference: impl Into<Span>,
+        n: u32,
+        max_variance: Option<u64>,
+    ) -> Result<(), Error> {
+        let counts = self
+            .ads
+            .read_averaged_blocking(delay, self.channel, n, max_variance)?;
+
+        self.set_span(reference, counts.saturating_sub(self.tare))
+    }
+
+    /// Reads the channel once and returns the weight, with the driver's
+    /// corrections, the tare, the span, and any limits applied
+    pub fn read_weight_blocking(&mut self, delay: &mut impl DelayNs) -> Weight {
+        let counts = self
+            .ads
+            .read_calibrated_blocking(delay, self.channel)
+            .corrected;
+        self.last_counts = Some(counts as i64);
+
+        let weight = self.counts_to_weight(counts);
+        self.clamp_weight(weight)
+    }
+
+    /// Averages `n` reads (at least one) and returns the weight with the same
+    /// corrections as [`Self::read_weight_blocking`]
+    ///
+    /// If `max_variance` is given the weighing is rejected with
+    /// [`Error::Unstable`] when the reads vary by more than that (see
+    /// [`ADS123X::read_averaged_blocking`]), so that a weight taken while the
+    /// load was still swinging is never reported.
+    pub fn read_weight_averaged_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        n: u32,
+        max_variance: Option<u64>,
+    ) -> Result<Weight, Error> {
+        let counts = self
+            .ads
+            .read_averaged_blocking(delay, self.channel, n, max_variance)?;
+        self.last_counts = Some(counts as i64);
+
+        let weight = self.counts_to_weight(counts);
+        Ok(self.clamp_weight(weight))
+    }
+
+    /// Reads the channel once and returns the weight in micrograms, carrying
+    /// the reading in 64 bits throughout (see [`ADS123X::read_wide_blocking`])
+    pub fn read_micrograms_blocking(&mut self, delay: &mut impl DelayNs) -> i64 {
+        let counts = self.ads.read_wide_blocking(delay, self.channel);
+        self.last_counts = Some(counts);
+
+        let micrograms = self.counts_to_micrograms(counts);
+        self.clamp_micrograms(micrograms)
+    }
+
+    /// Duty-cycles the converter until the weight on the scale exceeds
+    /// `threshold`, and returns the first weight that did
+    ///
+    /// The channel is read once every `interval_us` microseconds, with the chip
+    /// in standby in between, and [`AdsHooks::on_busy_wait`] is called roughly
+    /// once per millisecond while sleeping. The chip is left awake once the
+    /// threshold is crossed. Returns [`Error::WakeTimeout`] if the chip fails
+    /// to wake from standby.
+    pub fn wait_for_weight_above_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        threshold: Weight,
+        interval_us: u32,
+    ) -> Result<Weight, Error> {
+        loop {
+            if self.ads.power_state() == PowerState::Standby {
+                self.ads.wake_blocking(delay)?;
+            }
+
+            let weight = self.read_weight_blocking(delay);
+
+            if weight > threshold {
+                return Ok(weight);
+            }
+
+            self.ads.enter_standby_blocking(delay);
+            self.ads.sleep_blocking(delay, interval_us);
+        }
+    }
+
+    /// Duty-cycles the converter until the weight on the scale exceeds
+    /// `threshold`, reading it once every `interval`, see
+    /// [`Self::wait_for_weight_above_blocking`]
+    #[cfg(feature = "fugit")]
+    pub fn wait_for_weight_above_every_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        threshold: Weight,
+        interval: fugit::MicrosDurationU32,
+    ) -> Result<Weight, Error> {
+        self.wait_for_weight_above_blocking(delay, threshold, interval.ticks())
+    }
+
+    /// Reads the channel once and returns the force, with the driver's
+    /// corrections, the tare, the span, and any limits applied
+    pub fn read_force_blocking(&mut self, delay: &mut impl DelayNs) -> Force {
+        let counts = self
+            .ads
+            .read_calibrated_blocking(delay, self.channel)
+            .corrected;
+        self.last_counts = Some(counts as i64);
+
+        let force = self.counts_to_force(counts);
+        self.clamp_force(force)
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> Scale<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin + embedded_hal_async::digital::Wait,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Averages `n` reads (at least one) asynchronously with the scale empty
+    /// and stores the result as the tare
+    ///
+    /// See [`Self::tare_blocking`] for details.
+    pub async fn tare(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        n: u32,
+        max_variance: Option<u64>,
+    ) -> Result<i32, Error> {
+        let tare = self
+            .ads
+            .read_averaged(delay, self.channel, n, max_variance)
+            .await?;
+        self.tare = tare;
+        self.last_counts = Some(tare as i64);
+
+        Ok(tare)
+    }
+
+    /// Restores a persisted tare and span, and checks them against the average
+    /// of `n` asynchronous reads (at least one) of the empty scale
+    ///
+    /// See [`Self::restore_blocking`] for details.
+    pub async fn restore(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        tare: i32,
+        span: (Span, i32),
+        max_zero_drift: Weight,
+        n: u32,
+        max_variance: Option<u64>,
+    ) -> Result<ZeroCheck, Error> {
+        check_span(span.0, span.1)?;
+
+        let counts = self
+            .ads
+            .read_averaged(delay, self.channel, n, max_variance)
+            .await?;
+
+        Ok(self.apply_restored(tare, span, max_zero_drift, counts))
+    }
+
+    /// Averages `n` reads (at least one) asynchronously with a known reference
+    /// weight or force on the scale and stores the resulting span
+    ///
+    /// See [`Self::calibrate_span_blocking`] for details.
+    pub async fn calibrate_span(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        reference: impl Into<Span>,
+        n: u32,
+        max_variance: Option<u64>,
+    ) -> Result<(), Error> {
+        let counts = self
+            .ads
+            .read_averaged(delay, self.channel, n, max_variance)
+            .await?;
+
+        self.set_span(reference, counts.saturating_sub(self.tare))
+    }
+
+    /// Reads the channel once asynchronously and returns the weight
+    pub async fn read_weight(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> Weight {
+        let counts = self
+            .ads
+            .read_calibrated(delay, self.channel)
+            .await
+            .corrected;
+        self.last_counts = Some(counts as i64);
+
+        let weight = self.counts_to_weight(counts);
+        self.clamp_weight(weight)
+    }
+
+    /// Averages `n` reads (at least one) asynchronously and returns the weight
+    ///
+    /// See [`Self::read_weight_averaged_blocking`] for details.
+    pub async fn read_weight_averaged(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        n: u32,
+        max_variance: Option<u64>,
+    ) -> Result<Weight, Error> {
+        let counts = self
+            .ads
+            .read_averaged(delay, self.channel, n, max_variance)
+            .await?;
+        self.last_counts = Some(counts as i64);
+
+        let weight = self.counts_to_weight(counts);
+        Ok(self.clamp_weight(weight))
+    }
+
+    /// Reads the channel once asynchronously and returns the weight in
+    /// micrograms, carrying the reading in 64 bits throughout
+    pub async fn read_micrograms(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> i64 {
+        let counts = self.ads.read_wide(delay, self.channel).await;
+        self.last_counts = Some(counts);
+
+        let micrograms = self.counts_to_micrograms(counts);
+        self.clamp_micrograms(micrograms)
+    }
+
+    /// Duty-cycles the converter asynchronously until the weight on the scale
+    /// exceeds `threshold`, and returns the first weight that did
+    ///
+    /// See [`Self::wait_for_weight_above_blocking`] for details.
+    pub async fn wait_for_weight_above(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        threshold: Weight,
+        interval_us: u32,
+    ) -> Result<Weight, Error> {
+        loop {
+            if self.ads.power_state() == PowerState::Standby {
+                self.ads.wake(delay).await?;
+            }
+
+            let weight = self.read_weight(delay).await;
+
+            if weight > threshold {
+                return Ok(weight);
+            }
+
+            self.ads.enter_standby().await;
+            delay.delay_us(interval_us).await;
+        }
+    }
+
+    /// Duty-cycles the converter asynchronously until the weight on the scale
+    /// exceeds `threshold`, reading it once every `interval`, see
+    /// [`Self::wait_for_weight_above_blocking`]
+    #[cfg(feature = "fugit")]
+    pub async fn wait_for_weight_above_every(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        threshold: Weight,
+        interval: fugit::MicrosDurationU32,
+    ) -> Result<Weight, Error> {
+        self.wait_for_weight_above(delay, threshold, interval.ticks())
+            .await
+    }
+
+    /// Reads the channel once asynchronously and returns the force
+    pub async fn read_force(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> Force {
+        let counts = self
+            .ads
+            .read_calibrated(delay, self.channel)
+            .await
+            .corrected;
+        self.last_counts = Some(counts as i64);
+
+        let force = self.counts_to_force(counts);
+        self.clamp_force(force)
+    }
+}
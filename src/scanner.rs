@@ -0,0 +1,153 @@
+//! Round-robin scanning of several inputs
+//!
+//! A [`Scanner`] reads a list of channels one after another and keeps
+//! [`RunningStats`] for each of them, so that monitoring applications can
+//! summarize every input without keeping their own bookkeeping next to the
+//! driver. Each switch between channels throws away the conversions the model
+//! needs to settle, so a full scan takes considerably longer than a
+//! conversion period per channel.
+
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{InputPin, OutputPin, StatefulOutputPin},
+};
+
+use crate::{stats::RunningStats, ADSModel, AdsHooks, Clock, TimingProfile, ADS123X, MAX_CHANNELS};
+
+/// Reads a list of channels in turn and tracks the statistics of each
+pub struct Scanner<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>,
+    channels: &'static [M::Channel],
+    next: usize,
+    stats: [RunningStats; MAX_CHANNELS],
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> Scanner<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Creates a scanner over every channel of the model
+    pub fn new(ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>) -> Self {
+        Self {
+            ads,
+            channels: M::CHANNELS,
+            next: 0,
+            stats: [RunningStats::new(); MAX_CHANNELS],
+        }
+    }
+
+    /// Scans only the given channels, in the given order
+    ///
+    /// An empty list scans every channel of the model.
+    pub fn with_channels(mut self, channels: &'static [M::Channel]) -> Self {
+        self.channels = if channels.is_empty() {
+            M::CHANNELS
+        } else {
+            channels
+        };
+        self.next = 0;
+        self
+    }
+
+    /// Releases the driver
+    pub fn free(self) -> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> {
+        self.ads
+    }
+
+    /// Channel that will be read next
+    pub fn next_channel(&self) -> M::Channel {
+        self.channels[self.next]
+    }
+
+    /// Statistics of the corrected readings of the given channel since the
+    /// scanner was created or last reset
+    pub fn stats(&self, channel: M::Channel) -> &RunningStats {
+        &self.stats[M::channel_index(channel)]
+    }
+
+    /// Discards the statistics of every channel
+    pub fn reset_stats(&mut self) {
+        for stats in self.stats.iter_mut() {
+            stats.reset();
+        }
+    }
+
+    /// Reads the next channel in the list and returns it along with its
+    /// corrected value
+    pub fn scan_blocking(&mut self, delay: &mut impl DelayNs) -> (M::Channel, i32) {
+        let channel = self.advance();
+        let value = self.ads.read_calibrated_blocking(delay, channel).corrected;
+
+        self.stats[M::channel_index(channel)].push(value);
+        (channel, value)
+    }
+
+    /// Reads every channel in the list once, starting from the next one
+    pub fn scan_all_blocking(&mut self, delay: &mut impl DelayNs) {
+        for _ in 0..self.channels.len() {
+            self.scan_blocking(delay);
+        }
+    }
+
+    /// Returns the channel due to be read and moves on to the following one
+    fn advance(&mut self) -> M::Channel {
+        let channel = self.channels[self.next];
+        self.next = (self.next + 1) % self.channels.len();
+
+        channel
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> Scanner<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin + embedded_hal_async::digital::Wait,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Reads the next channel in the list asynchronously and returns it along
+    /// with its corrected value
+    pub async fn scan(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+    ) -> (M::Channel, i32) {
+        let channel = self.advance();
+        let value = self.ads.read_calibrated(delay, channel).await.corrected;
+
+        self.stats[M::channel_index(channel)].push(value);
+        (channel, value)
+    }
+
+    /// Reads every channel in the list once asynchronously, starting from the
+    /// next one
+    pub async fn scan_all(&mut self, delay: &mut impl embedded_hal_async::delay::DelayNs) {
+        for _ in 0..self.channels.len() {
+            self.scan(delay).await;
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! Production test of the analog front end
+//!
+//! [`ADS123X::self_check_blocking`] (or [`ADS123X::self_check`]) runs the
+//! sequence a test fixture would otherwise script itself: it resets the chip,
+//! checks that it starts converting, performs an offset calibration, and then
+//! reads every channel to check that it is neither saturated nor noisier than
+//! allowed. Unless the chip doesn't respond at all or the calibration times
+//! out, every channel is checked and reported in a [`SelfCheckReport`] rather
+//! than stopping at the first failure, so that a fixture can log the whole
+//! picture.
+
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{InputPin, OutputPin, StatefulOutputPin},
+};
+
+use crate::{stats::RunningStats, ADSModel, AdsHooks, Clock, TimingProfile, ADS123X, MAX_CHANNELS};
+
+/// Outcome of reading one channel during a self-check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ChannelCheck {
+    /// Mean of the raw (uncorrected) readings
+    pub mean: i32,
+    /// Population standard deviation of the raw readings in counts
+    pub noise: u64,
+    /// Whether any reading was at the positive or negative full-scale code
+    pub saturated: bool,
+}
+
+impl ChannelCheck {
+    /// Whether the channel is unsaturated and at most `max_noise` counts
+    /// noisy
+    pub const fn passed(&self, max_noise: u64) -> bool {
+        !self.saturated && self.noise <= max_noise
+    }
+}
+
+/// Outcome of [`ADS123X::self_check_blocking`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SelfCheckReport {
+    /// Whether DRDY went low within [`crate::Speed::wake_timeout_us`] after
+    /// the reset
+    ///
+    /// The remaining steps are skipped if it didn't, as they would wait for
+    /// DRDY forever.
+    pub responded: bool,
+    /// Time the offset calibration took in microseconds as measured by the
+    /// driver's [`crate::Clock`], or `None` if it wasn't reached or didn't
+    /// complete within [`crate::Speed::calibration_timeout_us`]
+    ///
+    /// The channels are skipped if it timed out.
+    pub calibration_us: Option<u64>,
+    /// Outcome of every channel of the model, indexed like
+    /// [`ADSModel::CHANNELS`], or `None` for the ones that weren't read
+    pub channels: [Option<ChannelCheck>; MAX_CHANNELS],
+    /// Noise limit the channels were checked against
+    pub max_noise: u64,
+}
+
+impl SelfCheckReport {
+    /// Time the offset calibration took, see [`Self::calibration_us`]
+    #[cfg(feature = "fugit")]
+    pub fn calibration(&self) -> Option<fugit::MicrosDurationU64> {
+        self.calibration_us
+            .map(fugit::MicrosDurationU64::from_ticks)
+    }
+
+    /// Whether the chip responded, the offset calibration completed, and every
+    /// channel passed its check
+    pub fn passed(&self) -> bool {
+        self.responded
+            && self.calibration_us.is_some()
+            && self
+                .channels
+                .iter()
+                .flatten()
+                .all(|channel| channel.passed(self.max_noise))
+    }
+}
+
+/// Collects the raw readings of one channel into a [`ChannelCheck`]
+struct ChannelCollector {
+    stats: RunningStats,
+    saturated: bool,
+    full_scale: i32,
+}
+
+impl ChannelCollector {
+    fn new(full_scale: i32) -> Self {
+        Self {
+            stats: RunningStats::new(),
+            saturated: false,
+            full_scale,
+        }
+    }
+
+    fn push(&mut self, raw: i32) {
+        self.stats.push(raw);
+        self.saturated |= raw >= self.full_scale || raw < -self.full_scale;
+    }
+
+    fn finish(&self) -> ChannelCheck {
+        ChannelCheck {
+            mean: self.stats.mean().unwrap_or(0),
+            noise: self.stats.std_dev().unwrap_or(0),
+            saturated: self.saturated,
+        }
+    }
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Resets the chip, checks that it responds, calibrates its offset, and
+    /// reads every channel `reads` times (at least once), checking that none
+    /// of them saturates or varies by more than `max_noise` counts (standard
+    /// deviation)
+    ///
+    /// This takes several seconds at 10 SPS, as channel changes need settling
+    /// conversions. The chip is left awake on the last channel.
+    pub fn self_check_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+        reads: u32,
+        max_noise: u64,
+    ) -> SelfCheckReport {
+        let mut report = SelfCheckReport {
+            responded: false,
+            calibration_us: None,
+            channels: [None; MAX_CHANNELS],
+            max_noise,
+        };
+
+        self.reset_blocking(delay);
+
+        if self.wake_blocking(delay).is_err() {
+            return report;
+        }
+        report.responded = true;
+
+        match self.calibrate_offset_blocking(delay) {
+            Ok(duration_us) => report.calibration_us = Some(duration_us),
+            Err(_) => return report,
+        }
+
+        for (slot, &channel) in report.channels.iter_mut().zip(M::CHANNELS) {
+            let mut collector = ChannelCollector::new(M::FULL_SCALE_POSITIVE);
+
+            for _ in 0..reads.max(1) {
+                collector.push(self.read_calibrated_blocking(delay, channel).raw);
+            }
+
+            *slot = Some(collector.finish());
+        }
+
+        report
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin + embedded_hal_async::digital::Wait,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Resets the chip, checks that it responds, calibrates its offset, and
+    /// reads every channel asynchronously
+    ///
+    /// See [`Self::self_check_blocking`] for details.
+    pub async fn self_check(
+        &mut self,
+        delay: &mut impl embedded_hal_async::delay::DelayNs,
+        reads: u32,
+        max_noise: u64,
+    ) -> SelfCheckReport {
+        let mut report = SelfCheckReport {
+            responded: false,
+            calibration_us: None,
+            channels: [None; MAX_CHANNELS],
+            max_noise,
+        };
+
+        self.reset(delay).await;
+
+        if self.wake(delay).await.is_err() {
+            return report;
+        }
+        report.responded = true;
+
+        match self.calibrate_offset(delay).await {
+            Ok(duration_us) => report.calibration_us = Some(duration_us),
+            Err(_) => return report,
+        }
+
+        for (slot, &channel) in report.channels.iter_mut().zip(M::CHANNELS) {
+            let mut collector = ChannelCollector::new(M::FULL_SCALE_POSITIVE);
+
+            for _ in 0..reads.max(1) {
+                collector.push(self.read_calibrated(delay, channel).await.raw);
+            }
+
+            *slot = Some(collector.finish());
+        }
+
+        report
+    }
+}
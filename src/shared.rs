@@ -0,0 +1,183 @@
+//! Per-channel handles to a single shared converter
+//!
+//! A [`Shared`] driver lives in a [`RefCell`] and hands out lightweight
+//! [`ChannelHandle`]s, each of which is bound to one input. Subsystems that
+//! own a single input can then read it without knowing about the mux or the
+//! other channels, and the driver takes care of switching and settling when
+//! consecutive reads come from different handles.
+//!
+//! Handles only provide blocking reads, as the driver has to stay borrowed for
+//! the whole conversion.
+
+use core::cell::RefCell;
+
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{InputPin, OutputPin, StatefulOutputPin},
+};
+
+#[cfg(feature = "ads1234")]
+use crate::{ADS1234Channel, ADS1234};
+use crate::{ADSModel, AdsHooks, CalibratedReading, Clock, TimingProfile, ADS123X};
+
+/// A driver that can be read from through any number of [`ChannelHandle`]s
+pub struct Shared<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    ads: RefCell<ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>>,
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> Shared<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    pub fn new(ads: ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>) -> Self {
+        Self {
+            ads: RefCell::new(ads),
+        }
+    }
+
+    /// Releases the driver
+    pub fn free(self) -> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> {
+        self.ads.into_inner()
+    }
+
+    /// Returns a handle that reads from the given channel
+    pub fn handle(
+        &self,
+        channel: M::Channel,
+    ) -> ChannelHandle<'_, DOUT, SCLK, PWDN, A0, A1, M, C, H, T> {
+        ChannelHandle {
+            ads: &self.ads,
+            channel,
+        }
+    }
+
+    /// Runs a closure with exclusive access to the driver, e.g. to calibrate
+    /// it or change its configuration
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within a read through one of the handles.
+    pub fn with<R>(
+        &self,
+        f: impl FnOnce(&mut ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>) -> R,
+    ) -> R {
+        f(&mut self.ads.borrow_mut())
+    }
+}
+
+#[cfg(feature = "ads1234")]
+impl<DOUT, SCLK, PWDN, A0, A1, C, H, T> Shared<DOUT, SCLK, PWDN, A0, A1, ADS1234, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Returns handles for AIN1 through AIN4, in that order
+    pub fn handles(&self) -> [ChannelHandle<'_, DOUT, SCLK, PWDN, A0, A1, ADS1234, C, H, T>; 4] {
+        [
+            self.handle(ADS1234Channel::AIN1),
+            self.handle(ADS1234Channel::AIN2),
+            self.handle(ADS1234Channel::AIN3),
+            self.handle(ADS1234Channel::AIN4),
+        ]
+    }
+}
+
+#[cfg(feature = "ads1234")]
+impl<DOUT, SCLK, PWDN, A0, A1, C, H, T> ADS123X<DOUT, SCLK, PWDN, A0, A1, ADS1234, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Moves the driver into a [`Shared`] cell, whose [`Shared::handles`]
+    /// method returns one handle for each of the four inputs
+    pub fn into_channels(self) -> Shared<DOUT, SCLK, PWDN, A0, A1, ADS1234, C, H, T> {
+        Shared::new(self)
+    }
+}
+
+/// A handle to one channel of a [`Shared`] driver
+pub struct ChannelHandle<'a, DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    ads: &'a RefCell<ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>>,
+    channel: M::Channel,
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> ChannelHandle<'_, DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Returns the channel this handle reads from
+    pub fn channel(&self) -> M::Channel {
+        self.channel
+    }
+
+    /// Reads from this handle's channel and returns the corrected value
+    ///
+    /// # Panics
+    ///
+    /// Panics if the driver is already borrowed through [`Shared::with`].
+    pub fn read_blocking(&self, delay: &mut impl DelayNs) -> i32 {
+        self.read_calibrated_blocking(delay).corrected
+    }
+
+    /// Reads from this handle's channel and returns the conversion result both
+    /// as it was read and with the channel's corrections applied
+    ///
+    /// # Panics
+    ///
+    /// Panics if the driver is already borrowed through [`Shared::with`].
+    pub fn read_calibrated_blocking(&self, delay: &mut impl DelayNs) -> CalibratedReading {
+        self.ads
+            .borrow_mut()
+            .read_calibrated_blocking(delay, self.channel)
+    }
+}
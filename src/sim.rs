@@ -0,0 +1,583 @@
+//! Host-side simulation of a converter
+//!
+//! A [`Simulator`] models the chip behind the serial interface closely enough
+//! to run the real driver against it: conversions complete once per period,
+//! frames are shifted out on SCLK, the extra pulses terminate a frame or start
+//! an offset calibration, switching channels restarts the conversion, holding
+//! SCLK high enters standby, and PWDN powers the chip down. Time is simulated
+//! too and only advances through the [`SimDelay`] (or waits on the
+//! [`SimDout`]), so a minute of readings runs in a fraction of a second and
+//! every run with the same seed is identical.
+//!
+//! Each input is modelled as a constant set with [`Simulator::set_input`],
+//! plus any load steps, slow zero drift, an offset proportional to the
+//! temperature, and gaussian noise. This is enough to tune and regression-test
+//! filters, stability detection, and drift compensation on the host.
+//!
+//! With [`Simulator::with_frame_log`], every conversion the chip publishes is
+//! also logged as a [`SimFrame`] along with the number of SCLK pulses it saw,
+//! which is what the [`crate::conformance`] checks are built on.
+//!
+//! The brief high pulse of DOUT before a conversion replaces an unterminated
+//! frame isn't modelled, so drivers configured with
+//! [`crate::TrailingPulse::Never`] don't work against the simulator.
+
+use core::{cell::RefCell, convert::Infallible, marker::PhantomData};
+
+use std::{rc::Rc, vec::Vec};
+
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{ErrorType, InputPin, OutputPin, StatefulOutputPin},
+};
+
+use crate::{suspend::Pins, ADSModel, Clock, Speed, MAX_CHANNELS};
+
+/// Time SCLK has to be held high before a conversion for the chip to enter
+/// standby instead, in nanoseconds
+const STANDBY_SCLK_HIGH_NS: u64 = 10_000;
+
+/// Number of conversion periods an offset calibration takes
+const CALIBRATION_PERIODS: u64 = 8;
+
+/// A change of one input by a number of counts from a point in time onwards
+#[derive(Debug, Clone, Copy)]
+struct LoadStep {
+    at_ns: u64,
+    channel: usize,
+    counts: i32,
+}
+
+/// A conversion the chip published, see [`Simulator::with_frame_log`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimFrame {
+    /// Index of the input that was converted (0 for AIN1, 1 for AIN2, and so
+    /// on), or `None` if the mux pins were in an invalid state
+    pub channel: Option<usize>,
+    /// Conversion result as a right aligned frame
+    pub data: u32,
+    /// Number of SCLK pulses the chip saw while this was its latest
+    /// conversion, up to the next one, a channel switch, standby, or
+    /// power-down
+    pub pulses: u32,
+}
+
+#[derive(Debug)]
+struct Chip {
+    bits: u32,
+    period_ns: u64,
+    wake_ns: u64,
+    decode_mux: fn(bool, bool) -> Option<usize>,
+    now_ns: u64,
+    /// Time the next conversion completes, or `None` in standby and
+    /// power-down
+    next_conversion_ns: Option<u64>,
+    sclk: bool,
+    sclk_high_since_ns: u64,
+    pwdn: bool,
+    a0: bool,
+    a1: bool,
+    dout: bool,
+    data: u32,
+    /// Number of SCLK pulses since the last conversion, or `None` if there is
+    /// no conversion to read
+    pulses: Option<u32>,
+    conversions: u64,
+    missed: u64,
+    inputs: [i32; MAX_CHANNELS],
+    steps: Vec<LoadStep>,
+    noise_counts: f64,
+    drift_counts_per_hour: f64,
+    offset_tempco: f64,
+    temperature_c: f64,
+    rng: u64,
+    log: Option<Vec<SimFrame>>,
+}
+
+impl Chip {
+    fn advance_to(&mut self, target_ns: u64) {
+        while let Some(conversion_ns) = self.next_conversion_ns {
+            if conversion_ns > target_ns {
+                break;
+            }
+
+            self.now_ns = conversion_ns;
+            self.convert();
+        }
+
+        self.now_ns = self.now_ns.max(target_ns);
+    }
+
+    fn convert(&mut self) {
+        if self.sclk && self.now_ns - self.sclk_high_since_ns >= STANDBY_SCLK_HIGH_NS {
+            self.next_conversion_ns = None;
+            self.pulses = None;
+            self.dout = true;
+            return;
+        }
+
+        self.next_conversion_ns = Some(self.now_ns + self.period_ns);
+        self.conversions += 1;
+
+        match self.pulses {
+            // A frame that is being shifted out isn't overwritten
+            Some(pulses) if pulses > 0 && pulses < self.bits => {
+                self.missed += 1;
+                return;
+            }
+            Some(0) => self.missed += 1,
+            _ => {}
+        }
+
+        self.data = self.sample();
+
+        if let Some(log) = self.log.as_mut() {
+            log.push(SimFrame {
+                channel: (self.decode_mux)(self.a0, self.a1),
+                data: self.data,
+                pulses: 0,
+            });
+        }
+        self.pulses = Some(0);
+        self.dout = false;
+    }
+
+    /// Returns the next conversion result as a right aligned frame
+    fn sample(&mut self) -> u32 {
+        let channel = (self.decode_mux)(self.a0, self.a1);
+
+        let mut value = channel.map_or(0.0, |channel| {
+            let steps: i64 = self
+                .steps
+                .iter()
+                .filter(|step| step.channel == channel && step.at_ns <= self.now_ns)
+                .map(|step| step.counts as i64)
+                .sum();
+
+            (self.inputs[channel] as i64 + steps) as f64
+        });
+
+        let hours = self.now_ns as f64 / 3_600_000_000_000.0;
+        value += self.drift_counts_per_hour * hours;
+        value += self.offset_tempco * (self.temperature_c - 25.0);
+        value += self.noise_counts * self.gaussian();
+
+        let max = ((1i64 << (self.bits - 1)) - 1) as f64;
+        let counts = value.round().clamp(-max - 1.0, max) as i64;
+
+        (counts as u32) & (u32::MAX >> (32 - self.bits))
+    }
+
+    /// Returns a normally distributed value with unit standard deviation
+    fn gaussian(&mut self) -> f64 {
+        // Box-Muller transform of two uniform values in (0, 1]
+        let u1 = self.uniform();
+        let u2 = self.uniform();
+
+        (-2.0 * u1.ln()).sqrt() * (core::f64::consts::TAU * u2).cos()
+    }
+
+    fn uniform(&mut self) -> f64 {
+        // xorshift64*
+        self.rng ^= self.rng >> 12;
+        self.rng ^= self.rng << 25;
+        self.rng ^= self.rng >> 27;
+        let bits = self.rng.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 11;
+
+        (bits + 1) as f64 / (1u64 << 53) as f64
+    }
+
+    fn set_sclk(&mut self, high: bool) {
+        if high && !self.sclk {
+            self.sclk_high_since_ns = self.now_ns;
+
+            if let Some(pulses) = self.pulses.as_mut() {
+                *pulses += 1;
+                let pulses = *pulses;
+
+                if let Some(frame) = self.log.as_mut().and_then(|log| log.last_mut()) {
+                    frame.pulses = pulses;
+                }
+
+                if pulses <= self.bits {
+                    self.dout = (self.data >> (self.bits - pulses)) & 1 != 0;
+                } else if pulses == self.bits + 1 {
+                    self.dout = true;
+                } else if pulses == self.bits + 2 {
+                    self.next_conversion_ns =
+                        Some(self.now_ns + CALIBRATION_PERIODS * self.period_ns);
+                }
+            }
+        } else if !high && self.sclk && self.pwdn && self.next_conversion_ns.is_none() {
+            // Waking up from standby
+            self.next_conversion_ns = Some(self.now_ns + self.wake_ns);
+        }
+
+        self.sclk = high;
+    }
+
+    fn set_mux(&mut self, a0: bool, a1: bool) {
+        // Switching channels resets the digital filter, so the next data is
+        // ready once it has settled
+        if (a0, a1) != (self.a0, self.a1) && self.next_conversion_ns.is_some() {
+            self.next_conversion_ns = Some(self.now_ns + self.wake_ns);
+            self.pulses = None;
+            self.dout = true;
+        }
+
+        self.a0 = a0;
+        self.a1 = a1;
+    }
+
+    fn set_pwdn(&mut self, high: bool) {
+        if high && !self.pwdn {
+            self.next_conversion_ns = Some(self.now_ns + self.wake_ns);
+        } else if !high {
+            self.next_conversion_ns = None;
+            self.pulses = None;
+            self.dout = true;
+        }
+
+        self.pwdn = high;
+    }
+}
+
+type Shared = Rc<RefCell<Chip>>;
+
+/// A simulated converter of model `M`, running at a fixed data rate
+pub struct Simulator<M: ADSModel> {
+    chip: Shared,
+    _model: PhantomData<M>,
+}
+
+impl<M: ADSModel> Simulator<M> {
+    /// Creates a converter that is already powered up, with every input at
+    /// zero counts and no noise, drift, or temperature dependence
+    pub fn new(speed: Speed) -> Self {
+        let period_ns = speed.conversion_period_us() as u64 * 1_000;
+
+        let chip = Chip {
+            bits: M::BITS,
+            period_ns,
+            wake_ns: speed.wake_time_us() as u64 * 1_000,
+            decode_mux: |a0, a1| M::decode_mux(a0, a1).map(M::channel_index),
+            now_ns: 0,
+            next_conversion_ns: Some(period_ns),
+            sclk: false,
+            sclk_high_since_ns: 0,
+            pwdn: true,
+            a0: false,
+            a1: false,
+            dout: true,
+            data: 0,
+            pulses: None,
+            conversions: 0,
+            missed: 0,
+            inputs: [0; MAX_CHANNELS],
+            steps: Vec::new(),
+            noise_counts: 0.0,
+            drift_counts_per_hour: 0.0,
+            offset_tempco: 0.0,
+            temperature_c: 25.0,
+            rng: 0x853c_49e6_748f_ea9b,
+            log: None,
+        };
+
+        Self {
+            chip: Rc::new(RefCell::new(chip)),
+            _model: PhantomData,
+        }
+    }
+
+    /// Seeds the noise generator, so that different runs see different noise
+    pub fn with_seed(self, seed: u64) -> Self {
+        // xorshift gets stuck at zero
+        self.chip.borrow_mut().rng = seed.max(1);
+        self
+    }
+
+    /// Adds gaussian noise with the given standard deviation in counts to
+    /// every conversion
+    pub fn with_noise(self, std_dev_counts: f64) -> Self {
+        self.chip.borrow_mut().noise_counts = std_dev_counts;
+        self
+    }
+
+    /// Lets the zero of every input drift linearly by the given number of
+    /// counts per hour of simulated time
+    pub fn with_drift(self, counts_per_hour: f64) -> Self {
+        self.chip.borrow_mut().drift_counts_per_hour = counts_per_hour;
+        self
+    }
+
+    /// Offsets every input by the given number of counts per degree Celsius
+    /// away from 25 °C (see [`Self::set_temperature`])
+    pub fn with_offset_tempco(self, counts_per_celsius: f64) -> Self {
+        self.chip.borrow_mut().offset_tempco = counts_per_celsius;
+        self
+    }
+
+    /// Logs every conversion the chip publishes from now on, see
+    /// [`Self::frames`]
+    pub fn with_frame_log(self) -> Self {
+        self.chip.borrow_mut().log = Some(Vec::new());
+        self
+    }
+
+    /// Returns the conversions logged so far, oldest first, or nothing
+    /// without [`Self::with_frame_log`]
+    ///
+    /// The pulse count of the latest one may still grow until the chip
+    /// replaces it.
+    pub fn frames(&self) -> Vec<SimFrame> {
+        self.chip.borrow().log.clone().unwrap_or_default()
+    }
+
+    /// Returns the pins to construct the driver from
+    ///
+    /// The pins share the simulated chip, so they can be requested more than
+    /// once (e.g. for several drivers in turn).
+    pub fn pins(&self) -> Pins<SimDout, SimPin, SimPin, SimPin, SimPin> {
+        let pin = |role| SimPin {
+            chip: self.chip.clone(),
+            role,
+        };
+
+        Pins {
+            dout: SimDout {
+                chip: self.chip.clone(),
+            },
+            sclk: pin(Role::Sclk),
+            pwdn: pin(Role::Pwdn),
+            a0: pin(Role::A0),
+            a1: pin(Role::A1),
+        }
+    }
+
+    /// Returns a delay that advances the simulated time
+    pub fn delay(&self) -> SimDelay {
+        SimDelay {
+            chip: self.chip.clone(),
+        }
+    }
+
+    /// Returns a clock that reads the simulated time
+    pub fn clock(&self) -> SimClock {
+        SimClock {
+            chip: self.chip.clone(),
+        }
+    }
+
+    /// Microseconds of simulated time since the simulator was created
+    pub fn now_us(&self) -> u64 {
+        self.chip.borrow().now_ns / 1_000
+    }
+
+    /// Advances the simulated time without touching any pin
+    pub fn advance_us(&self, us: u64) {
+        let mut chip = self.chip.borrow_mut();
+        let target_ns = chip.now_ns + us * 1_000;
+        chip.advance_to(target_ns);
+    }
+
+    /// Sets the value in counts the given input converts to, before load
+    /// steps, drift, temperature, and noise
+    pub fn set_input(&self, channel: M::Channel, counts: i32) {
+        self.chip.borrow_mut().inputs[M::channel_index(channel)] = counts;
+    }
+
+    /// Sets the temperature of the chip in degrees Celsius
+    pub fn set_temperature(&self, celsius: f64) {
+        self.chip.borrow_mut().temperature_c = celsius;
+    }
+
+    /// Changes the given input by `counts` from `at_us` microseconds of
+    /// simulated time onwards, e.g. to model an item being placed on a scale
+    pub fn add_load_step(&self, at_us: u64, channel: M::Channel, counts: i32) {
+        self.chip.borrow_mut().steps.push(LoadStep {
+            at_ns: at_us * 1_000,
+            channel: M::channel_index(channel),
+            counts,
+        });
+    }
+
+    /// Number of conversions the chip has completed
+    pub fn conversions(&self) -> u64 {
+        self.chip.borrow().conversions
+    }
+
+    /// Number of conversions that were overwritten before being read, or
+    /// dropped because a frame was being shifted out
+    pub fn missed_conversions(&self) -> u64 {
+        self.chip.borrow().missed
+    }
+}
+
+/// The DOUT pin of a [`Simulator`]
+pub struct SimDout {
+    chip: Shared,
+}
+
+impl ErrorType for SimDout {
+    type Error = Infallible;
+}
+
+impl InputPin for SimDout {
+    fn is_high(&mut self) -> Result<bool, Infallible> {
+        Ok(self.chip.borrow().dout)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Infallible> {
+        Ok(!self.chip.borrow().dout)
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl SimDout {
+    /// Advances the simulated time until DOUT is at the given level
+    ///
+    /// Panics if the chip is in standby or powered down and DOUT would never
+    /// get there.
+    fn advance_until(&mut self, high: bool) {
+        let mut chip = self.chip.borrow_mut();
+
+        while chip.dout != high {
+            let conversion_ns = chip
+                .next_conversion_ns
+                .expect("simulated DOUT would never change");
+            chip.advance_to(conversion_ns);
+        }
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl embedded_hal_async::digital::Wait for SimDout {
+    async fn wait_for_high(&mut self) -> Result<(), Infallible> {
+        self.advance_until(true);
+        Ok(())
+    }
+
+    async fn wait_for_low(&mut self) -> Result<(), Infallible> {
+        self.advance_until(false);
+        Ok(())
+    }
+
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Infallible> {
+        self.advance_until(false);
+        self.advance_until(true);
+        Ok(())
+    }
+
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Infallible> {
+        self.advance_until(true);
+        self.advance_until(false);
+        Ok(())
+    }
+
+    async fn wait_for_any_edge(&mut self) -> Result<(), Infallible> {
+        let high = self.chip.borrow().dout;
+        self.advance_until(!high);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Sclk,
+    Pwdn,
+    A0,
+    A1,
+}
+
+/// An output pin (SCLK, PWDN, A0, or A1) of a [`Simulator`]
+pub struct SimPin {
+    chip: Shared,
+    role: Role,
+}
+
+impl SimPin {
+    fn set(&mut self, high: bool) {
+        let mut chip = self.chip.borrow_mut();
+
+        match self.role {
+            Role::Sclk => chip.set_sclk(high),
+            Role::Pwdn => chip.set_pwdn(high),
+            Role::A0 => {
+                let a1 = chip.a1;
+                chip.set_mux(high, a1);
+            }
+            Role::A1 => {
+                let a0 = chip.a0;
+                chip.set_mux(a0, high);
+            }
+        }
+    }
+
+    fn get(&self) -> bool {
+        let chip = self.chip.borrow();
+
+        match self.role {
+            Role::Sclk => chip.sclk,
+            Role::Pwdn => chip.pwdn,
+            Role::A0 => chip.a0,
+            Role::A1 => chip.a1,
+        }
+    }
+}
+
+impl ErrorType for SimPin {
+    type Error = Infallible;
+}
+
+impl OutputPin for SimPin {
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        self.set(false);
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        self.set(true);
+        Ok(())
+    }
+}
+
+impl StatefulOutputPin for SimPin {
+    fn is_set_high(&mut self) -> Result<bool, Infallible> {
+        Ok(self.get())
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, Infallible> {
+        Ok(!self.get())
+    }
+}
+
+/// A delay that advances the time of a [`Simulator`] instead of waiting
+pub struct SimDelay {
+    chip: Shared,
+}
+
+impl DelayNs for SimDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        let mut chip = self.chip.borrow_mut();
+        let target_ns = chip.now_ns + ns as u64;
+        chip.advance_to(target_ns);
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl embedded_hal_async::delay::DelayNs for SimDelay {
+    async fn delay_ns(&mut self, ns: u32) {
+        DelayNs::delay_ns(self, ns);
+    }
+}
+
+/// A clock that reads the time of a [`Simulator`]
+pub struct SimClock {
+    chip: Shared,
+}
+
+impl Clock for SimClock {
+    fn now_us(&mut self) -> u64 {
+        self.chip.borrow().now_ns / 1_000
+    }
+}
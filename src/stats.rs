@@ -0,0 +1,308 @@
+//! Running statistics over streams of readings
+
+/// Accumulates readings to compute their mean and variance without storing
+/// them
+///
+/// The sums are kept in wide integers, so even full-scale readings can be
+/// accumulated for over four billion readings (more than a year and a half at
+/// 80 SPS) without overflowing. Once that many have been accumulated, further
+/// readings are ignored rather than wrapping the sums around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Accumulator {
+    count: u32,
+    sum: i64,
+    sum_of_squares: i128,
+}
+
+impl Accumulator {
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            sum: 0,
+            sum_of_squares: 0,
+        }
+    }
+
+    pub fn push(&mut self, value: i32) {
+        let Some(count) = self.count.checked_add(1) else {
+            return;
+        };
+
+        self.count = count;
+        self.sum += value as i64;
+        self.sum_of_squares += value as i128 * value as i128;
+    }
+
+    /// Number of readings accumulated so far
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Mean of the accumulated readings (truncated towards zero), or `None` if
+    /// nothing has been accumulated
+    pub fn mean(&self) -> Option<i32> {
+        if self.count == 0 {
+            return None;
+        }
+
+        Some((self.sum / self.count as i64) as i32)
+    }
+
+    /// Population variance of the accumulated readings in counts squared, or
+    /// `None` if nothing has been accumulated
+    pub fn variance(&self) -> Option<u64> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let n = self.count as i128;
+        let sum = self.sum as i128;
+
+        // n * Σx² - (Σx)² is n² times the variance and is never negative
+        let scaled = n * self.sum_of_squares - sum * sum;
+
+        Some((scaled / (n * n)).clamp(0, u64::MAX as i128) as u64)
+    }
+}
+
+/// Running minimum, maximum, mean, and standard deviation of a stream of
+/// readings, without storing them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RunningStats {
+    accumulator: Accumulator,
+    min: i32,
+    max: i32,
+}
+
+impl RunningStats {
+    pub const fn new() -> Self {
+        Self {
+            accumulator: Accumulator::new(),
+            min: i32::MAX,
+            max: i32::MIN,
+        }
+    }
+
+    pub fn push(&mut self, value: i32) {
+        self.accumulator.push(value);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// Number of readings seen so far
+    pub fn count(&self) -> u32 {
+        self.accumulator.count()
+    }
+
+    /// Smallest reading seen, or `None` if there hasn't been any
+    pub fn min(&self) -> Option<i32> {
+        (self.count() > 0).then_some(self.min)
+    }
+
+    /// Largest reading seen, or `None` if there hasn't been any
+    pub fn max(&self) -> Option<i32> {
+        (self.count() > 0).then_some(self.max)
+    }
+
+    /// See [`Accumulator::mean`]
+    pub fn mean(&self) -> Option<i32> {
+        self.accumulator.mean()
+    }
+
+    /// See [`Accumulator::variance`]
+    pub fn variance(&self) -> Option<u64> {
+        self.accumulator.variance()
+    }
+
+    /// Population standard deviation in counts (rounded down), or `None` if
+    /// there hasn't been any reading
+    pub fn std_dev(&self) -> Option<u64> {
+        self.variance().map(u64::isqrt)
+    }
+
+    /// Discards all readings
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for RunningStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Detects when a stream of values has settled, by checking that the last `N`
+/// values all lie within a band of a given width
+#[derive(Debug, Clone)]
+pub struct StabilityDetector<const N: usize> {
+    window: [i64; N],
+    len: usize,
+    next: usize,
+    tolerance: u64,
+}
+
+impl<const N: usize> StabilityDetector<N> {
+    /// Creates a detector that considers the stream stable once the last `N`
+    /// values differ from each other by at most `tolerance`
+    pub const fn new(tolerance: u64) -> Self {
+        Self {
+            window: [0; N],
+            len: 0,
+            next: 0,
+            tolerance,
+        }
+    }
+
+    /// Adds a value to the window and returns whether the stream is stable
+    pub fn push(&mut self, value: i64) -> bool {
+        if N == 0 {
+            return true;
+        }
+
+        self.window[self.next] = value;
+        self.next = (self.next + 1) % N;
+        self.len = (self.len + 1).min(N);
+
+        self.is_stable()
+    }
+
+    /// Returns whether the window is full and its values lie within the
+    /// tolerance of each other
+    pub fn is_stable(&self) -> bool {
+        if self.len < N {
+            return false;
+        }
+
+        let window = &self.window[..self.len];
+        let (Some(min), Some(max)) = (window.iter().min(), window.iter().max()) else {
+            return true;
+        };
+
+        max.abs_diff(*min) <= self.tolerance
+    }
+
+    /// Discards the values in the window
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.next = 0;
+    }
+}
+
+/// Running sums for one averaging time of an [`AllanDeviation`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct AllanLevel {
+    block_sum: i64,
+    block_len: u32,
+    previous: Option<i64>,
+    sum_of_squares: i128,
+    differences: u32,
+}
+
+/// Allan deviation of a stream of readings at `K` averaging times of 1, 2, 4,
+/// ... up to `2^(K-1)` conversions
+///
+/// Averaging reduces white noise, but only up to the point where slow drift
+/// (e.g. from temperature) starts to dominate. The Allan deviation at an
+/// averaging time is the typical difference between consecutive averages of
+/// that length, so the averaging time at which it bottoms out is the longest
+/// filter worth using, and anything longer only makes the result worse.
+///
+/// This uses the non-overlapping estimator, which only keeps a few sums per
+/// averaging time rather than the readings themselves. An estimate gets more
+/// trustworthy the more pairs of averages it is based on, so the stream
+/// should cover many times the longest averaging time of interest. Multiply
+/// the averaging times by [`crate::Speed::conversion_period_us`] to convert
+/// them to seconds. `K` can be at most 32.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AllanDeviation<const K: usize> {
+    levels: [AllanLevel; K],
+}
+
+impl<const K: usize> AllanDeviation<K> {
+    pub const fn new() -> Self {
+        Self {
+            levels: [AllanLevel {
+                block_sum: 0,
+                block_len: 0,
+                previous: None,
+                sum_of_squares: 0,
+                differences: 0,
+            }; K],
+        }
+    }
+
+    /// Adds a reading, which must directly follow the previous one
+    pub fn push(&mut self, value: i32) {
+        for (index, level) in self.levels.iter_mut().enumerate() {
+            level.block_sum += value as i64;
+            level.block_len += 1;
+
+            if level.block_len < Self::averaging_time(index) {
+                continue;
+            }
+
+            // Differences of the block sums are the differences of the
+            // averages scaled by the block length
+            if let Some(previous) = level.previous {
+                let difference = (level.block_sum - previous) as i128;
+
+                level.sum_of_squares = level.sum_of_squares.saturating_add(difference * difference);
+                level.differences = level.differences.saturating_add(1);
+            }
+
+            level.previous = Some(level.block_sum);
+            level.block_sum = 0;
+            level.block_len = 0;
+        }
+    }
+
+    /// Returns the number of conversions averaged at the given level, which is
+    /// `2^level`
+    pub const fn averaging_time(level: usize) -> u32 {
+        1 << level
+    }
+
+    /// Returns the Allan deviation at the given level in thousandths of a
+    /// count, or `None` if the level doesn't exist or fewer than two complete
+    /// averages have been seen at it
+    pub fn deviation_millicounts(&self, level: usize) -> Option<u64> {
+        let sums = self.levels.get(level)?;
+
+        if sums.differences == 0 {
+            return None;
+        }
+
+        // The Allan variance is half the mean squared difference of the
+        // averages, and scaling it by 10^6 scales the deviation by 10^3
+        let block_len = Self::averaging_time(level) as i128;
+        let mean_square = sums.sum_of_squares / sums.differences as i128;
+        let variance = mean_square.saturating_mul(1_000_000) / (2 * block_len * block_len);
+
+        Some((variance.min(u64::MAX as i128) as u64).isqrt())
+    }
+
+    /// Returns the level with the lowest Allan deviation, i.e. the most
+    /// effective number of conversions to average, along with the deviation in
+    /// thousandths of a count
+    pub fn best_level(&self) -> Option<(usize, u64)> {
+        (0..K)
+            .filter_map(|level| Some((level, self.deviation_millicounts(level)?)))
+            .min_by_key(|&(_, deviation)| deviation)
+    }
+
+    /// Discards all readings
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<const K: usize> Default for AllanDeviation<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,256 @@
+//! Handing the pins back temporarily without losing the driver's state
+//!
+//! Some devices have to give the GPIOs to something else for a while, e.g. a
+//! bootloader or an alternate function sharing the same pins.
+//! [`ADS123X::suspend_blocking`] (or [`ADS123X::suspend`]) parks the chip and
+//! splits the driver into its [`Pins`] and a [`Suspended`] driver holding
+//! everything else: the calibration, configuration, selected channel, clock,
+//! hooks, and counters. [`Suspended::resume`] puts the two back together.
+
+use core::marker::PhantomData;
+
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{InputPin, OutputPin, PinState, StatefulOutputPin},
+};
+
+use crate::{
+    ADSModel, AdsHooks, Clock, Config, Metrics, PowerProfile, PowerState, RateMeter, TimingProfile,
+    ADS123X, MAX_CHANNELS,
+};
+
+/// State the chip is parked in while the driver is suspended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SuspendMode {
+    /// Standby mode, which keeps the offset calibration but requires SCLK to
+    /// stay high while the pins are handed out
+    #[default]
+    Standby,
+    /// Power-down, which requires PWDN to stay low while the pins are handed
+    /// out and [`ADS123X::reset_blocking`] (or [`ADS123X::reset`]) after
+    /// resuming
+    PowerDown,
+}
+
+/// The pins of a driver
+#[derive(Debug)]
+pub struct Pins<DOUT, SCLK, PWDN, A0, A1> {
+    pub dout: DOUT,
+    pub sclk: SCLK,
+    pub pwdn: PWDN,
+    pub a0: A0,
+    pub a1: A1,
+}
+
+/// A driver whose pins have been handed out, see [`ADS123X::suspend_blocking`]
+pub struct Suspended<M: ADSModel, C, H, T> {
+    mode: SuspendMode,
+    channel: Option<M::Channel>,
+    clock: C,
+    hooks: H,
+    metrics: Metrics,
+    power_state_since_us: u64,
+    power_profile: PowerProfile,
+    sclk_write_ns: u32,
+    max_read_latency_us: Option<u32>,
+    offsets: [i32; MAX_CHANNELS],
+    gains: [i32; MAX_CHANNELS],
+    linearity: [i32; MAX_CHANNELS],
+    config: Config,
+    default_channel: M::Channel,
+    _timing: PhantomData<T>,
+}
+
+impl<M, C, H, T> Suspended<M, C, H, T>
+where
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// State the chip was parked in
+    pub fn mode(&self) -> SuspendMode {
+        self.mode
+    }
+
+    /// Rebuilds the driver from the given pins, driving the outputs back to
+    /// the levels they had when the driver was suspended
+    ///
+    /// The chip is left parked: a chip in standby wakes with the next read (or
+    /// [`ADS123X::wake_blocking`]), while one that was powered down needs a
+    /// reset first.
+    pub fn resume<DOUT, SCLK, PWDN, A0, A1>(
+        self,
+        pins: Pins<DOUT, SCLK, PWDN, A0, A1>,
+    ) -> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+    where
+        DOUT: InputPin,
+        SCLK: OutputPin,
+        PWDN: OutputPin,
+        A0: StatefulOutputPin,
+        A1: StatefulOutputPin,
+    {
+        let Pins {
+            dout,
+            mut sclk,
+            mut pwdn,
+            mut a0,
+            mut a1,
+        } = pins;
+
+        let (power_state, sclk_state, pwdn_state) = match self.mode {
+            SuspendMode::Standby => (PowerState::Standby, PinState::High, PinState::High),
+            SuspendMode::PowerDown => (PowerState::PoweredDown, PinState::Low, PinState::Low),
+        };
+
+        pwdn.set_state(pwdn_state).unwrap();
+        sclk.set_state(sclk_state).unwrap();
+
+        if let Some(channel) = self.channel {
+            let (a0_state, a1_state) = M::mux_state(channel);
+            a0.set_state(a0_state).unwrap();
+            a1.set_state(a1_state).unwrap();
+        }
+
+        ADS123X {
+            power_state,
+            power_state_since_us: self.power_state_since_us,
+            power_profile: self.power_profile,
+            sclk_write_ns: self.sclk_write_ns,
+            frame_unterminated: false,
+            rate: RateMeter::default(),
+            degenerate: None,
+            drdy_anchor_us: None,
+            max_read_latency_us: self.max_read_latency_us,
+            dout,
+            sclk,
+            pwdn,
+            a0,
+            a1,
+            clock: self.clock,
+            hooks: self.hooks,
+            metrics: self.metrics,
+            offsets: self.offsets,
+            gains: self.gains,
+            linearity: self.linearity,
+            config: self.config,
+            default_channel: self.default_channel,
+            _model: PhantomData,
+            _timing: PhantomData,
+        }
+    }
+}
+
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Parks the chip in the given mode (blocking until it has entered standby
+    /// if needed) and hands out the pins, keeping the rest of the driver for
+    /// [`Suspended::resume`]
+    ///
+    /// A chip that is already powered down is left powered down regardless of
+    /// `mode`.
+    pub fn suspend_blocking(
+        mut self,
+        delay: &mut impl DelayNs,
+        mode: SuspendMode,
+    ) -> (Pins<DOUT, SCLK, PWDN, A0, A1>, Suspended<M, C, H, T>) {
+        let mode = match (mode, self.power_state) {
+            // A chip that is already powered down stays that way
+            (_, PowerState::PoweredDown) => SuspendMode::PowerDown,
+            (SuspendMode::Standby, PowerState::Active) => {
+                self.enter_standby_blocking(delay);
+                mode
+            }
+            (SuspendMode::Standby, PowerState::Standby) => mode,
+            (SuspendMode::PowerDown, _) => {
+                self.power_down();
+                mode
+            }
+        };
+
+        self.split(mode)
+    }
+
+    /// Splits the parked driver into its pins and the rest of its state
+    fn split(
+        mut self,
+        mode: SuspendMode,
+    ) -> (Pins<DOUT, SCLK, PWDN, A0, A1>, Suspended<M, C, H, T>) {
+        let suspended = Suspended {
+            mode,
+            channel: self.selected_channel(),
+            clock: self.clock,
+            hooks: self.hooks,
+            metrics: self.metrics,
+            power_state_since_us: self.power_state_since_us,
+            power_profile: self.power_profile,
+            sclk_write_ns: self.sclk_write_ns,
+            max_read_latency_us: self.max_read_latency_us,
+            offsets: self.offsets,
+            gains: self.gains,
+            linearity: self.linearity,
+            config: self.config,
+            default_channel: self.default_channel,
+            _timing: PhantomData,
+        };
+
+        let pins = Pins {
+            dout: self.dout,
+            sclk: self.sclk,
+            pwdn: self.pwdn,
+            a0: self.a0,
+            a1: self.a1,
+        };
+
+        (pins, suspended)
+    }
+}
+
+#[cfg(feature = "embedded-hal-async")]
+impl<DOUT, SCLK, PWDN, A0, A1, M, C, H, T> ADS123X<DOUT, SCLK, PWDN, A0, A1, M, C, H, T>
+where
+    DOUT: InputPin + embedded_hal_async::digital::Wait,
+    SCLK: OutputPin,
+    PWDN: OutputPin,
+    A0: StatefulOutputPin,
+    A1: StatefulOutputPin,
+    M: ADSModel,
+    C: Clock,
+    H: AdsHooks,
+    T: TimingProfile,
+{
+    /// Parks the chip in the given mode (waiting asynchronously until it has
+    /// entered standby if needed) and hands out the pins, keeping the rest of
+    /// the driver for [`Suspended::resume`]
+    pub async fn suspend(
+        mut self,
+        mode: SuspendMode,
+    ) -> (Pins<DOUT, SCLK, PWDN, A0, A1>, Suspended<M, C, H, T>) {
+        let mode = match (mode, self.power_state) {
+            // A chip that is already powered down stays that way
+            (_, PowerState::PoweredDown) => SuspendMode::PowerDown,
+            (SuspendMode::Standby, PowerState::Active) => {
+                self.enter_standby().await;
+                mode
+            }
+            (SuspendMode::Standby, PowerState::Standby) => mode,
+            (SuspendMode::PowerDown, _) => {
+                self.power_down();
+                mode
+            }
+        };
+
+        self.split(mode)
+    }
+}
@@ -0,0 +1,119 @@
+//! Limit monitoring with hysteresis
+//!
+//! A [`ThresholdMonitor`] sorts a stream of values (e.g. the milligrams of a
+//! [`crate::units::Weight`]) into a low, normal, and high [`Zone`], and reports
+//! a [`ThresholdEvent`] whenever the zone changes, such as a hopper becoming
+//! full or running empty. Values are only classified once they have settled
+//! according to a [`StabilityDetector`], so material being poured in or a
+//! bump against the scale doesn't cause spurious events, and a value has to
+//! move back past a limit by the hysteresis before the zone is left again.
+
+use crate::stats::StabilityDetector;
+
+/// The range a value falls into relative to a [`ThresholdMonitor`]'s limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Zone {
+    /// Below the lower limit
+    Low,
+    /// Between the limits
+    Normal,
+    /// Above the upper limit
+    High,
+}
+
+/// A change of [`Zone`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ThresholdEvent {
+    pub from: Zone,
+    pub to: Zone,
+    /// The settled value that caused the change
+    pub value: i64,
+}
+
+impl ThresholdEvent {
+    pub fn entered(&self, zone: Zone) -> bool {
+        self.to == zone
+    }
+
+    pub fn exited(&self, zone: Zone) -> bool {
+        self.from == zone
+    }
+}
+
+/// Turns a stream of values into events when they settle outside of (or back
+/// within) a pair of limits, using a window of `N` values to check whether
+/// they have settled
+#[derive(Debug, Clone)]
+pub struct ThresholdMonitor<const N: usize> {
+    lower: i64,
+    upper: i64,
+    hysteresis: u64,
+    stability: StabilityDetector<N>,
+    zone: Zone,
+}
+
+impl<const N: usize> ThresholdMonitor<N> {
+    /// Creates a monitor for the given limits, which considers values settled
+    /// once the last `N` of them differ by at most `tolerance`
+    ///
+    /// The monitor starts out in [`Zone::Normal`], so a first settled value
+    /// outside of the limits is reported as an event.
+    pub const fn new(lower: i64, upper: i64, tolerance: u64) -> Self {
+        Self {
+            lower,
+            upper,
+            hysteresis: 0,
+            stability: StabilityDetector::new(tolerance),
+            zone: Zone::Normal,
+        }
+    }
+
+    /// Sets how far a value has to move back past a limit before the zone on
+    /// the far side of it is left again (none by default)
+    pub const fn with_hysteresis(mut self, hysteresis: u64) -> Self {
+        self.hysteresis = hysteresis;
+        self
+    }
+
+    pub fn zone(&self) -> Zone {
+        self.zone
+    }
+
+    /// Adds a value and returns an event if it settled in a different zone
+    pub fn push(&mut self, value: i64) -> Option<ThresholdEvent> {
+        if !self.stability.push(value) {
+            return None;
+        }
+
+        let hysteresis = self.hysteresis.min(i64::MAX as u64) as i64;
+        let zone = match self.zone {
+            Zone::High if value >= self.upper.saturating_sub(hysteresis) => Zone::High,
+            Zone::Low if value <= self.lower.saturating_add(hysteresis) => Zone::Low,
+            _ if value > self.upper => Zone::High,
+            _ if value < self.lower => Zone::Low,
+            _ => Zone::Normal,
+        };
+
+        if zone == self.zone {
+            return None;
+        }
+
+        let event = ThresholdEvent {
+            from: self.zone,
+            to: zone,
+            value,
+        };
+        self.zone = zone;
+
+        Some(event)
+    }
+
+    /// Returns to [`Zone::Normal`] and discards the values collected by the
+    /// stability check
+    pub fn reset(&mut self) {
+        self.stability.reset();
+        self.zone = Zone::Normal;
+    }
+}
@@ -0,0 +1,673 @@
+//! Fixed-point physical quantities produced by the driver
+
+use core::{
+    fmt::{self, Write},
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+/// An electrical potential stored as a whole number of nanovolts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct Voltage {
+    nanovolts: i64,
+}
+
+impl Voltage {
+    pub const ZERO: Self = Self::from_nanovolts(0);
+
+    pub const fn from_nanovolts(nanovolts: i64) -> Self {
+        Self { nanovolts }
+    }
+
+    pub const fn from_microvolts(microvolts: i64) -> Self {
+        Self::from_nanovolts(microvolts.saturating_mul(1_000))
+    }
+
+    pub const fn from_millivolts(millivolts: i64) -> Self {
+        Self::from_nanovolts(millivolts.saturating_mul(1_000_000))
+    }
+
+    pub const fn nanovolts(self) -> i64 {
+        self.nanovolts
+    }
+
+    /// Returns the voltage in whole microvolts (truncated towards zero)
+    pub const fn microvolts(self) -> i64 {
+        self.nanovolts / 1_000
+    }
+
+    /// Returns the voltage in whole millivolts (truncated towards zero)
+    pub const fn millivolts(self) -> i64 {
+        self.nanovolts / 1_000_000
+    }
+}
+
+/// The output of a bridge sensor relative to its excitation voltage, stored as
+/// a whole number of nanovolts per volt
+///
+/// Load cells are rated by their full-capacity output in millivolts per volt
+/// (e.g. 2 mV/V), which is independent of the excitation voltage used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BridgeOutput {
+    nanovolts_per_volt: i64,
+}
+
+impl BridgeOutput {
+    pub const ZERO: Self = Self::from_nanovolts_per_volt(0);
+
+    pub const fn from_nanovolts_per_volt(nanovolts_per_volt: i64) -> Self {
+        Self { nanovolts_per_volt }
+    }
+
+    pub const fn from_microvolts_per_volt(microvolts_per_volt: i64) -> Self {
+        Self::from_nanovolts_per_volt(microvolts_per_volt.saturating_mul(1_000))
+    }
+
+    pub const fn from_millivolts_per_volt(millivolts_per_volt: i64) -> Self {
+        Self::from_nanovolts_per_volt(millivolts_per_volt.saturating_mul(1_000_000))
+    }
+
+    pub const fn nanovolts_per_volt(self) -> i64 {
+        self.nanovolts_per_volt
+    }
+
+    /// Returns the output in whole microvolts per volt (truncated towards
+    /// zero)
+    pub const fn microvolts_per_volt(self) -> i64 {
+        self.nanovolts_per_volt / 1_000
+    }
+}
+
+/// Micrograms in an international avoirdupois pound
+const MICROGRAMS_PER_POUND: i128 = 453_592_370;
+
+/// Standard gravity (9.80665 m/s²) in micrometers per second squared
+pub(crate) const STANDARD_GRAVITY_UM_PER_S2: i128 = 9_806_650;
+
+/// Implements addition and subtraction of two quantities of the same kind,
+/// negation, and scaling by integers, for a type wrapping a single `i64`
+macro_rules! impl_arithmetic {
+    ($ty:ident, $field:ident) => {
+        impl Add for $ty {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self {
+                    $field: self.$field + rhs.$field,
+                }
+            }
+        }
+
+        impl AddAssign for $ty {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Sub for $ty {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self {
+                    $field: self.$field - rhs.$field,
+                }
+            }
+        }
+
+        impl SubAssign for $ty {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl Neg for $ty {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self {
+                    $field: -self.$field,
+                }
+            }
+        }
+
+        impl Mul<i64> for $ty {
+            type Output = Self;
+
+            fn mul(self, rhs: i64) -> Self {
+                Self {
+                    $field: self.$field * rhs,
+                }
+            }
+        }
+
+        /// Divides the quantity, truncating towards zero
+        impl Div<i64> for $ty {
+            type Output = Self;
+
+            fn div(self, rhs: i64) -> Self {
+                Self {
+                    $field: self.$field / rhs,
+                }
+            }
+        }
+
+        impl Sum for $ty {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, Add::add)
+            }
+        }
+    };
+}
+
+/// Narrows an intermediate result, saturating at the bounds of an i64
+pub(crate) const fn saturate(value: i128) -> i64 {
+    if value > i64::MAX as i128 {
+        i64::MAX
+    } else if value < i64::MIN as i128 {
+        i64::MIN
+    } else {
+        value as i64
+    }
+}
+
+/// A mass stored as a whole number of milligrams
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct Weight {
+    milligrams: i64,
+}
+
+impl Weight {
+    pub const ZERO: Self = Self::from_milligrams(0);
+
+    pub const fn from_milligrams(milligrams: i64) -> Self {
+        Self { milligrams }
+    }
+
+    pub const fn from_grams(grams: i64) -> Self {
+        Self::from_milligrams(grams.saturating_mul(1_000))
+    }
+
+    pub const fn from_kilograms(kilograms: i64) -> Self {
+        Self::from_milligrams(kilograms.saturating_mul(1_000_000))
+    }
+
+    /// Creates a weight from whole ounces (truncated to milligrams)
+    pub const fn from_ounces(ounces: i64) -> Self {
+        Self::from_milligrams(saturate(ounces as i128 * MICROGRAMS_PER_POUND / 16_000))
+    }
+
+    /// Creates a weight from whole pounds (truncated to milligrams)
+    pub const fn from_pounds(pounds: i64) -> Self {
+        Self::from_milligrams(saturate(pounds as i128 * MICROGRAMS_PER_POUND / 1_000))
+    }
+
+    pub const fn milligrams(self) -> i64 {
+        self.milligrams
+    }
+
+    /// Returns the weight in whole grams (truncated towards zero)
+    pub const fn grams(self) -> i64 {
+        self.milligrams / 1_000
+    }
+
+    /// Returns the weight in whole kilograms (truncated towards zero)
+    pub const fn kilograms(self) -> i64 {
+        self.milligrams / 1_000_000
+    }
+
+    /// Returns the weight in whole ounces (truncated towards zero)
+    pub const fn ounces(self) -> i64 {
+        (self.milligrams as i128 * 16_000 / MICROGRAMS_PER_POUND) as i64
+    }
+
+    /// Returns the weight in thousandths of an ounce (truncated towards zero)
+    pub const fn milliounces(self) -> i64 {
+        (self.milligrams as i128 * 16_000_000 / MICROGRAMS_PER_POUND) as i64
+    }
+
+    /// Returns the weight in whole pounds (truncated towards zero)
+    pub const fn pounds(self) -> i64 {
+        (self.milligrams as i128 * 1_000 / MICROGRAMS_PER_POUND) as i64
+    }
+
+    /// Returns the weight in thousandths of a pound (truncated towards zero)
+    pub const fn millipounds(self) -> i64 {
+        (self.milligrams as i128 * 1_000_000 / MICROGRAMS_PER_POUND) as i64
+    }
+
+    /// Returns the force this mass exerts under standard gravity (truncated to
+    /// millinewtons)
+    pub const fn to_force(self) -> Force {
+        Force::from_millinewtons(
+            (self.milligrams as i128 * STANDARD_GRAVITY_UM_PER_S2 / 1_000_000_000) as i64,
+        )
+    }
+
+    pub const fn abs(self) -> Self {
+        Self::from_milligrams(self.milligrams.saturating_abs())
+    }
+}
+
+impl_arithmetic!(Weight, milligrams);
+
+/// A force stored as a whole number of millinewtons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "ufmt", derive(ufmt::derive::uDebug))]
+pub struct Force {
+    millinewtons: i64,
+}
+
+impl Force {
+    pub const ZERO: Self = Self::from_millinewtons(0);
+
+    pub const fn from_millinewtons(millinewtons: i64) -> Self {
+        Self { millinewtons }
+    }
+
+    pub const fn from_newtons(newtons: i64) -> Self {
+        Self::from_millinewtons(newtons.saturating_mul(1_000))
+    }
+
+    pub const fn from_kilonewtons(kilonewtons: i64) -> Self {
+        Self::from_millinewtons(kilonewtons.saturating_mul(1_000_000))
+    }
+
+    pub const fn millinewtons(self) -> i64 {
+        self.millinewtons
+    }
+
+    /// Returns the force in whole newtons (truncated towards zero)
+    pub const fn newtons(self) -> i64 {
+        self.millinewtons / 1_000
+    }
+
+    /// Returns the force in whole kilonewtons (truncated towards zero)
+    pub const fn kilonewtons(self) -> i64 {
+        self.millinewtons / 1_000_000
+    }
+
+    /// Returns the mass that exerts this force under standard gravity
+    /// (truncated to milligrams)
+    pub const fn to_weight(self) -> Weight {
+        Weight::from_milligrams(saturate(
+            self.millinewtons as i128 * 1_000_000_000 / STANDARD_GRAVITY_UM_PER_S2,
+        ))
+    }
+
+    pub const fn abs(self) -> Self {
+        Self::from_millinewtons(self.millinewtons.saturating_abs())
+    }
+}
+
+impl_arithmetic!(Force, millinewtons);
+
+/// A unit a quantity can be formatted in with [`InUnit`]
+pub trait Unit: Copy + crate::private::Sealed {
+    /// Returns the numerator and denominator converting the quantity's stored
+    /// integer to this unit
+    #[doc(hidden)]
+    fn ratio(self) -> (i128, i128);
+
+    /// Symbol appended after the number
+    fn symbol(self) -> &'static str;
+
+    /// Number of decimal places shown when the format string doesn't specify a
+    /// precision, which is enough to show the full resolution of the quantity
+    fn default_decimals(self) -> usize;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WeightUnit {
+    Milligrams,
+    Grams,
+    Kilograms,
+    Ounces,
+    Pounds,
+}
+
+impl crate::private::Sealed for WeightUnit {}
+
+impl Unit for WeightUnit {
+    fn ratio(self) -> (i128, i128) {
+        match self {
+            Self::Milligrams => (1, 1),
+            Self::Grams => (1, 1_000),
+            Self::Kilograms => (1, 1_000_000),
+            Self::Ounces => (16_000, MICROGRAMS_PER_POUND),
+            Self::Pounds => (1_000, MICROGRAMS_PER_POUND),
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Milligrams => "mg",
+            Self::Grams => "g",
+            Self::Kilograms => "kg",
+            Self::Ounces => "oz",
+            Self::Pounds => "lb",
+        }
+    }
+
+    fn default_decimals(self) -> usize {
+        match self {
+            Self::Milligrams => 0,
+            Self::Grams => 3,
+            Self::Kilograms => 6,
+            Self::Ounces => 4,
+            Self::Pounds => 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ForceUnit {
+    Millinewtons,
+    Newtons,
+    Kilonewtons,
+}
+
+impl crate::private::Sealed for ForceUnit {}
+
+impl Unit for ForceUnit {
+    fn ratio(self) -> (i128, i128) {
+        match self {
+            Self::Millinewtons => (1, 1),
+            Self::Newtons => (1, 1_000),
+            Self::Kilonewtons => (1, 1_000_000),
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Millinewtons => "mN",
+            Self::Newtons => "N",
+            Self::Kilonewtons => "kN",
+        }
+    }
+
+    fn default_decimals(self) -> usize {
+        match self {
+            Self::Millinewtons => 0,
+            Self::Newtons => 3,
+            Self::Kilonewtons => 6,
+        }
+    }
+}
+
+/// Units of [`Voltage`], whose symbols are written in ASCII (`uV` rather than
+/// `µV`) so that they can be shown on character displays
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum VoltageUnit {
+    Nanovolts,
+    Microvolts,
+    Millivolts,
+    Volts,
+}
+
+impl crate::private::Sealed for VoltageUnit {}
+
+impl Unit for VoltageUnit {
+    fn ratio(self) -> (i128, i128) {
+        match self {
+            Self::Nanovolts => (1, 1),
+            Self::Microvolts => (1, 1_000),
+            Self::Millivolts => (1, 1_000_000),
+            Self::Volts => (1, 1_000_000_000),
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Self::Nanovolts => "nV",
+            Self::Microvolts => "uV",
+            Self::Millivolts => "mV",
+            Self::Volts => "V",
+        }
+    }
+
+    fn default_decimals(self) -> usize {
+        match self {
+            Self::Nanovolts => 0,
+            Self::Microvolts => 3,
+            Self::Millivolts => 6,
+            Self::Volts => 9,
+        }
+    }
+}
+
+/// A quantity formatted in a given unit, as returned by e.g.
+/// [`Weight::in_unit`]
+///
+/// The precision of the format string selects the number of decimal places
+/// (rounded half away from zero), and the width, fill, and alignment are
+/// applied to the number and unit symbol together, so `{:>8.1}` renders a
+/// weight in grams as e.g. `" 123.5 g"`. Numbers are right aligned by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InUnit<U> {
+    value: i64,
+    unit: U,
+}
+
+impl Weight {
+    pub const fn in_unit(self, unit: WeightUnit) -> InUnit<WeightUnit> {
+        InUnit {
+            value: self.milligrams,
+            unit,
+        }
+    }
+}
+
+impl Force {
+    pub const fn in_unit(self, unit: ForceUnit) -> InUnit<ForceUnit> {
+        InUnit {
+            value: self.millinewtons,
+            unit,
+        }
+    }
+}
+
+impl Voltage {
+    pub const fn in_unit(self, unit: VoltageUnit) -> InUnit<VoltageUnit> {
+        InUnit {
+            value: self.nanovolts,
+            unit,
+        }
+    }
+}
+
+/// Largest number of decimal places that can be formatted, beyond which the
+/// precision is clamped
+const MAX_DECIMALS: usize = 12;
+
+impl<U: Unit> InUnit<U> {
+    /// Renders the number and unit symbol with the given number of decimal
+    /// places (rounded half away from zero)
+    fn render(&self, decimals: usize) -> Buffer {
+        let decimals = decimals.min(MAX_DECIMALS);
+        let (numerator, denominator) = self.unit.ratio();
+
+        // The value in units of 10^-decimals, rounded half away from zero
+        let scaled = self.value as i128 * numerator * 10i128.pow(decimals as u32);
+        let rounded = (scaled.abs() + denominator / 2) / denominator;
+
+        let mut buffer = Buffer::new();
+        let divisor = 10u128.pow(decimals as u32);
+        let magnitude = rounded as u128;
+
+        if scaled < 0 && rounded != 0 {
+            buffer.push('-');
+        }
+
+        write_digits(&mut buffer, magnitude / divisor, 1);
+
+        if decimals > 0 {
+            buffer.push('.');
+            write_digits(&mut buffer, magnitude % divisor, decimals);
+        }
+
+        buffer.push(' ');
+
+        for c in self.unit.symbol().chars() {
+            buffer.push(c);
+        }
+
+        buffer
+    }
+}
+
+impl<U: Unit> fmt::Display for InUnit<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let buffer = self.render(f.precision().unwrap_or(self.unit.default_decimals()));
+
+        let padding = f.width().unwrap_or(0).saturating_sub(buffer.len);
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (padding, 0),
+        };
+
+        for _ in 0..before {
+            f.write_char(f.fill())?;
+        }
+
+        f.write_str(buffer.as_str())?;
+
+        for _ in 0..after {
+            f.write_char(f.fill())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<U: Unit> InUnit<U> {
+    /// Renders the quantity right aligned to exactly `N` characters with the
+    /// given number of decimal places, e.g. for a character LCD or a
+    /// seven-segment display buffer, or returns `None` if it doesn't fit
+    ///
+    /// `weight.in_unit(WeightUnit::Kilograms).to_fixed_width::<8>(2)` renders
+    /// 1.5 kg as `" 1.50 kg"`.
+    pub fn to_fixed_width<const N: usize>(&self, decimals: usize) -> Option<heapless::String<N>> {
+        let mut string = heapless::String::new();
+
+        write!(string, "{:>width$.decimals$}", self, width = N).ok()?;
+
+        (string.len() == N).then_some(string)
+    }
+}
+
+/// Formats the quantity with the unit's default number of decimal places, as
+/// `ufmt` has no notion of precision
+#[cfg(feature = "ufmt")]
+impl<U: Unit> ufmt::uDisplay for InUnit<U> {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        f.write_str(self.render(self.unit.default_decimals()).as_str())
+    }
+}
+
+/// Writes the decimal digits of a number, zero padded to at least `min_digits`
+fn write_digits(buffer: &mut Buffer, mut value: u128, min_digits: usize) {
+    let mut digits = [0u8; 40];
+    let mut len = 0;
+
+    while value > 0 || len < min_digits {
+        digits[len] = b'0' + (value % 10) as u8;
+        value /= 10;
+        len += 1;
+    }
+
+    for &digit in digits[..len].iter().rev() {
+        buffer.push(digit as char);
+    }
+}
+
+/// Stack buffer a formatted quantity is assembled in before padding it
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Self {
+        Self {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    /// Appends an ASCII character, which is all the formatter produces
+    fn push(&mut self, c: char) {
+        if self.len < self.bytes.len() && c.is_ascii() {
+            self.bytes[self.len] = c as u8;
+            self.len += 1;
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+/// Formats the weight in grams, see [`InUnit`]
+impl fmt::Display for Weight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.in_unit(WeightUnit::Grams), f)
+    }
+}
+
+/// Formats the force in newtons, see [`InUnit`]
+impl fmt::Display for Force {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.in_unit(ForceUnit::Newtons), f)
+    }
+}
+
+/// Formats the voltage in millivolts, see [`InUnit`]
+impl fmt::Display for Voltage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.in_unit(VoltageUnit::Millivolts), f)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Weight {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt::uDisplay::fmt(&self.in_unit(WeightUnit::Grams), f)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Force {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt::uDisplay::fmt(&self.in_unit(ForceUnit::Newtons), f)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Voltage {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        ufmt::uDisplay::fmt(&self.in_unit(VoltageUnit::Millivolts), f)
+    }
+}
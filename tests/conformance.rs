@@ -0,0 +1,42 @@
+//! Runs the wire protocol checks against every model, so that refactors of
+//! the protocol code can't change what the chip sees unnoticed
+
+#![cfg(feature = "conformance")]
+
+use ads123x::{conformance, ADSModel, ADS1130, ADS1131};
+
+fn assert_conforms<M: ADSModel>() {
+    let violations = conformance::check::<M>();
+
+    assert!(
+        violations.is_empty(),
+        "{}",
+        violations
+            .iter()
+            .map(|violation| violation.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+#[cfg(feature = "ads1232")]
+#[test]
+fn ads1232() {
+    assert_conforms::<ads123x::ADS1232>();
+}
+
+#[cfg(feature = "ads1234")]
+#[test]
+fn ads1234() {
+    assert_conforms::<ads123x::ADS1234>();
+}
+
+#[test]
+fn ads1130() {
+    assert_conforms::<ADS1130>();
+}
+
+#[test]
+fn ads1131() {
+    assert_conforms::<ADS1131>();
+}